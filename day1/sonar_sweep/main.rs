@@ -1,24 +1,8 @@
-use std::fs::File;
-use std::io::{self, BufRead};
+use lvoytek_advent_of_code_2021::{day1, error, input};
 
 fn main() {
-    let input_file = File::open("files/sonar_input")
-        .expect("Unable to read from input");
+    let input_contents = input::input_from_args(1)
+        .unwrap_or_else(|err| error::report_and_exit(format!("Unable to read from input: {}", err)));
 
-    let lines = io::BufReader::new(input_file).lines();
-
-    let mut larger_count = 0;
-    let mut prev_value = i32::MAX;
-    for line in lines {
-        if let Ok(next_line) = line {
-            let int_value = next_line.parse::<i32>().unwrap();
-            if int_value > prev_value {
-                larger_count += 1;
-            }
-
-            prev_value = int_value;
-        }
-    }
-
-    println!("Number of increases: {}", larger_count)
+    println!("Number of increases: {}", day1::part1(&input_contents).unwrap_or_else(|err| error::report_and_exit(err)))
 }
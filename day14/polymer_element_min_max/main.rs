@@ -1,94 +1,23 @@
-use std::fs;
-use std::cmp::Ordering;
-
-#[derive(Clone)]
-struct Transfer<'a> {
-    input: &'a str,
-    insert: char,
-}
-
-#[derive(Clone, Eq)]
-struct ElementAmount {
-    element: char,
-    amount: u32,
-}
-
-impl PartialOrd for ElementAmount {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
-    }
-}
-
-impl Ord for ElementAmount {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.amount.cmp(&other.amount)
-    }
-}
-
-impl PartialEq for ElementAmount {
-    fn eq(&self, other: &Self) -> bool {
-        self.amount == other.amount
-    }
-}
+use lvoytek_advent_of_code_2021::{day14, error, input};
 
 fn main() {
-    let input_contents = fs::read_to_string("files/polymer_input")
-        .expect("Unable to read from input");
-
-    let mut lines = input_contents.lines().collect::<Vec<&str>>();
-
-    let mut elements : String = lines[0].to_string();
-
-    lines.remove(0);
-    lines.remove(0);
-
-    let mut transfers = Vec::<Transfer>::new();
+    let input_contents = input::input_from_args(14)
+        .unwrap_or_else(|err| error::report_and_exit(format!("Unable to read from input: {}", err)));
+    let verbose = input::verbose_from_args();
 
-    for line in lines {
-        let from_insert = line.split(" -> ").collect::<Vec<&str>>();
-
-        transfers.push(Transfer {
-            input: from_insert[0],
-            insert: from_insert[1].chars().nth(0).unwrap(),
-        });
-    }
+    let (mut elements, transfers) = day14::parse_polymer(&input_contents).unwrap_or_else(|err| error::report_and_exit(err));
 
     // Run through 10 steps
     for _ in 0..10 {
-        let mut i: usize = 0;
-        for _ in 0..elements.len() - 1 {
-            for transfer in &transfers {
-                if &elements[i..=i+1] == transfer.input {
-                    elements.insert(i+1, transfer.insert);
-                    i += 1;
-                    break;
-                }
-            }
-            i += 1;
-        }
+        day14::insert_elements(&mut elements, &transfers);
     }
 
-    // find min and max count elements
-    let mut count_buckets = Vec::<ElementAmount>::new();
-
-    for element in elements.chars() {
-        let mut element_found = false;
-        for i in 0..count_buckets.len() {
-            if element == count_buckets[i].element {
-                count_buckets[i].amount += 1;
-                element_found = true;
-            }
-        }
+    let count_buckets = day14::count_elements(&elements);
+    let cb_last_index = count_buckets.len() - 1;
 
-        if !element_found {
-            count_buckets.push(ElementAmount {
-                element: element,
-                amount: 1,
-            });
-        }
+    if verbose {
+        eprintln!("{} ({}) - {} ({})", count_buckets[cb_last_index].element, count_buckets[cb_last_index].amount, count_buckets[0].element, count_buckets[0].amount);
     }
 
-    count_buckets.sort();
-    let cb_last_index = count_buckets.len() - 1;
-    println!("{} ({}) - {} ({}) = {}", count_buckets[cb_last_index].element, count_buckets[cb_last_index].amount, count_buckets[0].element, count_buckets[0].amount, count_buckets[cb_last_index].amount - count_buckets[0].amount);
+    println!("Most minus least common: {}", count_buckets[cb_last_index].amount - count_buckets[0].amount);
 }
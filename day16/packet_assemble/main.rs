@@ -0,0 +1,11 @@
+use lvoytek_advent_of_code_2021::{day16, error, input};
+
+fn main() {
+    // Expressions are read from the file given, or from stdin without one
+    let expressions = input::input_or_stdin_from_args()
+        .unwrap_or_else(|err| error::report_and_exit(format!("Unable to read from input: {}", err)));
+
+    for transmission in day16::assemble_transmissions(&expressions).unwrap_or_else(|err| error::report_and_exit(err)) {
+        println!("{}", transmission);
+    }
+}
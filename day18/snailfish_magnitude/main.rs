@@ -1,9 +1,9 @@
-use std::fs;
+use lvoytek_advent_of_code_2021::{error, input};
 
 fn main() {
-    let input_contents = fs::read_to_string("files/snailfish_num_input")
-        .expect("Unable to read from input");
+    let input_contents = input::input_from_args(18)
+        .unwrap_or_else(|err| error::report_and_exit(format!("Unable to read from input: {}", err)));
 
-    let lines = input_contents.lines().collect::<Vec<&str>>();
+    let _lines = input_contents.lines().collect::<Vec<&str>>();
     
 }
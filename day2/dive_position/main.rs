@@ -1,32 +1,18 @@
-use std::fs::File;
-use std::io::{self, BufRead};
+use lvoytek_advent_of_code_2021::{day2, error, input};
 
 fn main() {
-    let input_file = File::open("files/dive_input")
-        .expect("Unable to read from input");
+    let input_contents = input::input_from_args(2)
+        .unwrap_or_else(|err| error::report_and_exit(format!("Unable to read from input: {}", err)));
+    let verbose = input::verbose_from_args();
 
-    let lines = io::BufReader::new(input_file).lines();
+    let position = day2::dive_position(&day2::parse_commands(&input_contents).unwrap_or_else(|err| error::report_and_exit(err)));
 
-    let mut horizontal_pos = 0;
-    let mut vertical_pos = 0;
+    let multi_pos = position.horizontal * position.depth;
 
-    for line in lines {
-        if let Ok(next_line) = line {
-            let position_vector = next_line.split(" ").collect::<Vec<&str>>();
-            let direction = position_vector[0];
-            let int_value = position_vector[1].parse::<i32>().unwrap();
-
-            match direction {
-                "forward"=>horizontal_pos+=int_value,
-                "down"=>vertical_pos+=int_value,
-                "up"=>vertical_pos-=int_value,
-                _=>println!("Parse Error: Bad position"),
-            }
-        }
+    if verbose {
+        eprintln!("Horizontal Position: {}", position.horizontal);
+        eprintln!("Vertical Position: {}", position.depth);
     }
 
-    let multi_pos = horizontal_pos * vertical_pos;
-    println!("Horizontal Position: {}", horizontal_pos);
-    println!("Vertical Position: {}", vertical_pos);
     println!("Vertical Position x Horizontal Position: {}", multi_pos);
 }
@@ -0,0 +1,25 @@
+use lvoytek_advent_of_code_2021::{day24, error, input};
+
+fn main() {
+    let input_contents = input::input_from_args(24)
+        .unwrap_or_else(|err| error::report_and_exit(format!("Unable to read from input: {}", err)));
+    let verbose = input::verbose_from_args();
+
+    let vars = day24::build_operations(&input_contents).unwrap_or_else(|err| error::report_and_exit(err));
+
+    let domains = match input::flag_value_from_args("--domain") {
+        Some(domain_text) => day24::parse_domains(&domain_text, day24::count_inputs(&vars)).unwrap_or_else(|err| error::report_and_exit(err)),
+        None => day24::default_domains(&vars),
+    };
+
+    if verbose {
+        let (optimized, _) = day24::optimize_operations(&vars, &domains);
+        day24::alu_print('z', optimized[3].top, &optimized);
+    }
+
+    let (program, stats) = day24::optimized_program(&vars, &domains).unwrap_or_else(|err| error::report_and_exit(err));
+
+    // The program goes to stdout so it can be saved and run, with how much it shrank going to stderr
+    eprintln!("{}", stats);
+    print!("{}", program);
+}
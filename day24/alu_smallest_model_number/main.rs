@@ -0,0 +1,26 @@
+use lvoytek_advent_of_code_2021::{day24, error, input};
+
+fn main() {
+    let input_contents = input::input_from_args(24)
+        .unwrap_or_else(|err| error::report_and_exit(format!("Unable to read from input: {}", err)));
+    let verbose = input::verbose_from_args();
+
+    let program = day24::parse_program(&input_contents).unwrap_or_else(|err| error::report_and_exit(err));
+    let vars = day24::build_operations(&input_contents).unwrap_or_else(|err| error::report_and_exit(err));
+
+    let domains = match input::flag_value_from_args("--domain") {
+        Some(domain_text) => day24::parse_domains(&domain_text, day24::count_inputs(&vars)).unwrap_or_else(|err| error::report_and_exit(err)),
+        None => day24::default_domains(&vars),
+    };
+
+    let (vars, stats) = day24::optimize_operations(&vars, &domains);
+
+    if verbose {
+        eprintln!("{} inputs", day24::count_inputs(&vars));
+        eprintln!("{}", stats);
+    }
+
+    let digits = day24::model_number_in(&vars, &program, &domains, day24::Preference::Smallest).unwrap_or_else(|err| error::report_and_exit(err));
+
+    println!("{}", day24::model_number_string(&digits));
+}
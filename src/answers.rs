@@ -0,0 +1,274 @@
+// Known correct answers for a set of inputs, kept next to the inputs in a small subset of TOML:
+//
+//   [day1]
+//   part1 = "1400"
+//   part2 = 1429
+//
+// Answers spanning several lines, such as day 13's letters, are written as ''' literal strings
+
+use std::collections::BTreeMap;
+
+use crate::error::{self, AocError, AocResult};
+
+pub const ANSWERS_FILE_NAME: &str = "answers.toml";
+
+const MULTI_LINE_QUOTES: &str = "'''";
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Answers {
+    // Keyed by day then part
+    answers: BTreeMap<(u32, u32), String>,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Verdict {
+    Pass,
+
+    // The answer did not match, holding the stored one
+    Fail(String),
+    Missing,
+
+    // The solver gave no answer, holding why
+    Error(String),
+
+    // There was no input to run the solver on, which is not a failure
+    Skipped,
+}
+
+// Get the number after a prefix such as the 3 in day3
+fn parse_numbered(text: &str, prefix: &str, max: u32) -> Option<u32> {
+    let number = text.strip_prefix(prefix)?.parse::<u32>().ok()?;
+
+    if number == 0 || number > max {
+        return None;
+    }
+
+    return Some(number);
+}
+
+// Make sure nothing but a comment follows a value
+fn check_trailing(rest: &str, line: &str, line_num: usize) -> AocResult<()> {
+    let rest = rest.trim();
+
+    if !rest.is_empty() && !rest.starts_with('#') {
+        return Err(AocError::parse(line_num, error::column_of(line, rest), format!("unexpected \"{}\" after answer", rest)));
+    }
+
+    return Ok(());
+}
+
+// Parse a double quoted string with backslash escapes, returning it and whatever follows the closing quote
+fn parse_basic_string<'a>(quoted: &'a str, line: &str, line_num: usize) -> AocResult<(String, &'a str)> {
+    let mut value = String::new();
+    let mut chars = quoted.char_indices().skip(1);
+
+    while let Some((char_index, val)) = chars.next() {
+        match val {
+            '"' => return Ok((value, &quoted[char_index + 1..])),
+            '\\' => match chars.next() {
+                Some((_, 'n')) => value.push('\n'),
+                Some((_, 't')) => value.push('\t'),
+                Some((_, '"')) => value.push('"'),
+                Some((_, '\\')) => value.push('\\'),
+                _ => return Err(AocError::parse(line_num, error::column_of(line, quoted) + char_index, "bad escape in answer")),
+            },
+            _ => value.push(val),
+        }
+    }
+
+    return Err(AocError::parse(line_num, error::column_of(line, quoted), "answer is missing its closing quote"));
+}
+
+impl Answers {
+    pub fn new() -> Answers {
+        return Answers::default();
+    }
+
+    pub fn parse(input: &str) -> AocResult<Answers> {
+        let lines = input.lines().collect::<Vec<&str>>();
+        let mut answers = Answers::new();
+        let mut current_day: Option<u32> = None;
+        let mut line_index = 0;
+
+        while line_index < lines.len() {
+            let line = lines[line_index];
+            let line_num = line_index + 1;
+            let trimmed = line.trim();
+            line_index += 1;
+
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+
+            // Table header naming the day
+            if let Some(header) = trimmed.strip_prefix('[') {
+                let day_name = match header.split_once(']') {
+                    Some((day_name, rest)) => {
+                        check_trailing(rest, line, line_num)?;
+                        day_name.trim()
+                    },
+                    None => return Err(AocError::parse(line_num, 1, "table header is missing its closing ]")),
+                };
+
+                match parse_numbered(day_name, "day", 25) {
+                    Some(day) => current_day = Some(day),
+                    None => return Err(AocError::parse(line_num, error::column_of(line, day_name), format!("expected day1 to day25, found \"{}\"", day_name))),
+                }
+
+                continue;
+            }
+
+            let (key, value) = match trimmed.split_once('=') {
+                Some((key, value)) => (key.trim(), value.trim_start()),
+                None => return Err(AocError::parse(line_num, error::column_of(line, trimmed), "expected part<N> = <answer>")),
+            };
+
+            let day = match current_day {
+                Some(day) => day,
+                None => return Err(AocError::parse(line_num, 1, "answer is not under a [day<N>] header")),
+            };
+
+            let part = match parse_numbered(key, "part", 2) {
+                Some(part) => part,
+                None => return Err(AocError::parse(line_num, error::column_of(line, key), format!("expected part1 or part2, found \"{}\"", key))),
+            };
+
+            let answer = if let Some(literal) = value.strip_prefix(MULTI_LINE_QUOTES) {
+                if let Some((answer, rest)) = literal.split_once(MULTI_LINE_QUOTES) {
+                    check_trailing(rest, line, line_num)?;
+                    answer.to_string()
+                }
+                else {
+                    // A newline straight after the opening quotes is not part of the answer
+                    let mut answer = if literal.is_empty() {String::new()} else {format!("{}\n", literal)};
+
+                    loop {
+                        if line_index >= lines.len() {
+                            return Err(AocError::parse(line_num, error::column_of(line, value), "answer is missing its closing '''"));
+                        }
+
+                        let literal_line = lines[line_index];
+                        line_index += 1;
+
+                        match literal_line.split_once(MULTI_LINE_QUOTES) {
+                            Some((last_part, rest)) => {
+                                check_trailing(rest, literal_line, line_index)?;
+                                answer.push_str(last_part);
+                                break;
+                            },
+                            None => {
+                                answer.push_str(literal_line);
+                                answer.push('\n');
+                            },
+                        }
+                    }
+
+                    answer
+                }
+            }
+            else if value.starts_with('"') {
+                let (answer, rest) = parse_basic_string(value, line, line_num)?;
+                check_trailing(rest, line, line_num)?;
+                answer
+            }
+            else {
+                let number = value.split('#').next().unwrap_or("").trim();
+
+                if number.is_empty() || number.parse::<i64>().is_err() {
+                    return Err(AocError::parse(line_num, error::column_of(line, value), "answer must be a quoted string or a whole number"));
+                }
+
+                number.to_string()
+            };
+
+            if answers.answers.insert((day, part), answer).is_some() {
+                return Err(AocError::parse(line_num, 1, format!("day {} part {} is answered twice", day, part)));
+            }
+        }
+
+        return Ok(answers);
+    }
+
+    pub fn get(&self, day: u32, part: u32) -> Option<&str> {
+        return self.answers.get(&(day, part)).map(|answer| answer.as_str());
+    }
+
+    pub fn insert(&mut self, day: u32, part: u32, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    // Compare a solver's answer, or the reason it has none, with the stored answer
+    pub fn check(&self, day: u32, part: u32, answer: &Result<String, String>) -> Verdict {
+        match (answer, self.get(day, part)) {
+            (Err(err), _) => return Verdict::Error(err.clone()),
+            (Ok(_), None) => return Verdict::Missing,
+            (Ok(answer), Some(expected)) if answer == expected => return Verdict::Pass,
+            (Ok(_), Some(expected)) => return Verdict::Fail(expected.to_string()),
+        }
+    }
+
+    pub fn to_toml(&self) -> String {
+        let mut toml = "# Known correct answers for these inputs, checked by aoc2021 verify\n".to_string();
+        let mut last_day = 0;
+
+        for ((day, part), answer) in &self.answers {
+            if *day != last_day {
+                toml.push_str(&format!("\n[day{}]\n", day));
+                last_day = *day;
+            }
+
+            if answer.contains('\n') {
+                toml.push_str(&format!("part{} = {}\n{}{}\n", part, MULTI_LINE_QUOTES, answer, MULTI_LINE_QUOTES));
+            }
+            else {
+                toml.push_str(&format!("part{} = \"{}\"\n", part, answer.replace('\\', "\\\\").replace('"', "\\\"")));
+            }
+        }
+
+        return toml;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::parse("# comment\n[day1]\npart1 = \"1400\"\npart2 = 1429 # trailing\n\n[day13]\npart2 = '''\n# #\n###\n'''\n").unwrap();
+
+        assert_eq!(Some("1400"), answers.get(1, 1));
+        assert_eq!(Some("1429"), answers.get(1, 2));
+        assert_eq!(Some("# #\n###\n"), answers.get(13, 2));
+        assert_eq!(None, answers.get(2, 1));
+    }
+
+    #[test]
+    fn rejects_bad_answers() {
+        assert_eq!(AocError::parse(1, 1, "answer is not under a [day<N>] header"), Answers::parse("part1 = 3\n").unwrap_err());
+        assert_eq!(AocError::parse(1, 2, "expected day1 to day25, found \"day26\""), Answers::parse("[day26]\n").unwrap_err());
+        assert_eq!(AocError::parse(2, 1, "expected part1 or part2, found \"part3\""), Answers::parse("[day1]\npart3 = 3\n").unwrap_err());
+        assert_eq!(AocError::parse(2, 9, "answer is missing its closing quote"), Answers::parse("[day1]\npart1 = \"3\n").unwrap_err());
+        assert_eq!(AocError::parse(3, 1, "day 1 part 1 is answered twice"), Answers::parse("[day1]\npart1 = 3\npart1 = 4\n").unwrap_err());
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let mut answers = Answers::new();
+        answers.insert(13, 2, "#  #\n####\n".to_string());
+        answers.insert(1, 1, "say \"hi\"".to_string());
+        answers.insert(1, 2, "1429".to_string());
+
+        assert_eq!(answers, Answers::parse(&answers.to_toml()).unwrap());
+    }
+
+    #[test]
+    fn checks_answers() {
+        let answers = Answers::parse("[day1]\npart1 = 7\n").unwrap();
+
+        assert_eq!(Verdict::Pass, answers.check(1, 1, &Ok("7".to_string())));
+        assert_eq!(Verdict::Fail("7".to_string()), answers.check(1, 1, &Ok("8".to_string())));
+        assert_eq!(Verdict::Missing, answers.check(1, 2, &Ok("5".to_string())));
+        assert_eq!(Verdict::Error("oops".to_string()), answers.check(1, 1, &Err("oops".to_string())));
+    }
+}
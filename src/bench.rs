@@ -0,0 +1,120 @@
+// Timing input parsing and solving separately over repeated runs
+
+use std::hint;
+use std::panic;
+use std::time::{Duration, Instant};
+
+use crate::error::AocResult;
+use crate::input::{self, InputSource};
+use crate::runner::Solver;
+
+pub const DEFAULT_ITERATIONS: u32 = 10;
+
+// Every run's time for each phase, in the order they were run
+pub struct PhaseTimes {
+    pub parse: Vec<Duration>,
+    pub solve: Vec<Duration>,
+}
+
+pub struct Summary {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+pub struct BenchResult {
+    // The phase times, or a description of why there are none
+    pub times: Result<PhaseTimes, String>,
+    pub input: InputSource,
+}
+
+// Parse then solve the input the given number of times, timing each phase on its own
+// Solving takes ownership of the parsed input so solvers that modify it get a fresh copy every run
+pub fn time_phases<'a, T, A>(input: &'a str, iterations: u32, parse: fn(&'a str) -> AocResult<T>, solve: fn(T) -> AocResult<A>) -> AocResult<PhaseTimes> {
+    let mut times = PhaseTimes {
+        parse: Vec::<Duration>::new(),
+        solve: Vec::<Duration>::new(),
+    };
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = hint::black_box(parse(hint::black_box(input))?);
+        times.parse.push(start.elapsed());
+
+        let start = Instant::now();
+        hint::black_box(solve(parsed)?);
+        times.solve.push(start.elapsed());
+    }
+
+    return Ok(times);
+}
+
+pub fn summarize(times: &[Duration]) -> Summary {
+    let mut sorted_times = times.to_vec();
+    sorted_times.sort();
+
+    if sorted_times.is_empty() {
+        return Summary {
+            min: Duration::ZERO,
+            median: Duration::ZERO,
+            max: Duration::ZERO,
+        };
+    }
+
+    return Summary {
+        min: sorted_times[0],
+        median: sorted_times[sorted_times.len() / 2],
+        max: sorted_times[sorted_times.len() - 1],
+    };
+}
+
+// Read the solver's input and time its phases, catching any panic along the way
+pub fn run_bench(solver: &Solver, input_path: Option<&str>, iterations: u32) -> BenchResult {
+    let source = input::resolve_input(input_path, solver.day);
+
+    let input_contents = match input::read_input(&source) {
+        Ok(contents) => contents,
+        Err(err) => {
+            return BenchResult {
+                times: Err(format!("Unable to read from input: {}", err)),
+                input: source,
+            };
+        },
+    };
+
+    let times = match panic::catch_unwind(|| (solver.bench)(&input_contents, iterations)) {
+        Ok(Ok(times)) => Ok(times),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(_) => Err("Solver panicked".to_string()),
+    };
+
+    return BenchResult {
+        times,
+        input: source,
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day1;
+
+    #[test]
+    fn summarizes_times() {
+        let times = [5, 1, 4, 2, 3].map(Duration::from_millis);
+        let summary = summarize(&times);
+
+        assert_eq!(Duration::from_millis(1), summary.min);
+        assert_eq!(Duration::from_millis(3), summary.median);
+        assert_eq!(Duration::from_millis(5), summary.max);
+    }
+
+    #[test]
+    fn times_each_run() {
+        let times = time_phases("1\n2\n", 3, day1::parse_depths, |depths| Ok(day1::count_increases(&depths))).unwrap();
+        assert_eq!(3, times.parse.len());
+        assert_eq!(3, times.solve.len());
+
+        assert!(time_phases("1\nx\n", 3, day1::parse_depths, |depths| Ok(day1::count_increases(&depths))).is_err());
+    }
+}
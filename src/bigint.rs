@@ -0,0 +1,211 @@
+// Unsigned integers of any size, for values that do not fit in 64 bits
+
+use std::cmp::Ordering;
+use std::fmt;
+
+// Stored as 32 bit limbs from least to most significant, without any zero limbs at the top
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct BigUint {
+    limbs: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> BigUint {
+        return BigUint::default();
+    }
+
+    pub fn is_zero(&self) -> bool {
+        return self.limbs.is_empty();
+    }
+
+    fn trim(&mut self) {
+        while self.limbs.last() == Some(&0) {
+            self.limbs.pop();
+        }
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        match self.limbs.len() {
+            0 => return Some(0),
+            1 => return Some(self.limbs[0] as u64),
+            2 => return Some((self.limbs[1] as u64) << 32 | self.limbs[0] as u64),
+            _ => return None,
+        }
+    }
+
+    // Number of bits needed to write the value, 0 for zero
+    pub fn bit_len(&self) -> usize {
+        match self.limbs.last() {
+            Some(top_limb) => return 32 * self.limbs.len() - top_limb.leading_zeros() as usize,
+            None => return 0,
+        }
+    }
+
+    // Get the 4 bits starting at bit 4 * index, counting from the least significant end
+    pub fn nibble(&self, index: usize) -> u8 {
+        match self.limbs.get(index / 8) {
+            Some(limb) => return ((limb >> (4 * (index % 8))) & 0xF) as u8,
+            None => return 0,
+        }
+    }
+
+    // Shift left by 4 bits and put a nibble in the freed bits, for building a value a hex digit at a time
+    pub fn push_nibble(&mut self, nibble: u8) {
+        let mut carry = (nibble & 0xF) as u32;
+
+        for limb in self.limbs.iter_mut() {
+            let shifted_out = *limb >> 28;
+            *limb = *limb << 4 | carry;
+            carry = shifted_out;
+        }
+
+        if carry != 0 {
+            self.limbs.push(carry);
+        }
+    }
+
+    pub fn add(&self, other: &BigUint) -> BigUint {
+        let mut sum = BigUint {
+            limbs: Vec::<u32>::with_capacity(self.limbs.len().max(other.limbs.len()) + 1),
+        };
+
+        let mut carry: u64 = 0;
+
+        for limb_index in 0..self.limbs.len().max(other.limbs.len()) {
+            let limb_sum = *self.limbs.get(limb_index).unwrap_or(&0) as u64 + *other.limbs.get(limb_index).unwrap_or(&0) as u64 + carry;
+            sum.limbs.push(limb_sum as u32);
+            carry = limb_sum >> 32;
+        }
+
+        if carry != 0 {
+            sum.limbs.push(carry as u32);
+        }
+
+        return sum;
+    }
+
+    pub fn mul(&self, other: &BigUint) -> BigUint {
+        let mut product = BigUint {
+            limbs: vec![0; self.limbs.len() + other.limbs.len()],
+        };
+
+        for i in 0..self.limbs.len() {
+            let mut carry: u64 = 0;
+
+            for j in 0..other.limbs.len() {
+                let limb_product = self.limbs[i] as u64 * other.limbs[j] as u64 + product.limbs[i + j] as u64 + carry;
+                product.limbs[i + j] = limb_product as u32;
+                carry = limb_product >> 32;
+            }
+
+            product.limbs[i + other.limbs.len()] = carry as u32;
+        }
+
+        product.trim();
+        return product;
+    }
+
+    // Divide in place by a small divisor, returning the remainder
+    fn div_rem_small(&mut self, divisor: u32) -> u32 {
+        let mut remainder: u64 = 0;
+
+        for limb in self.limbs.iter_mut().rev() {
+            let dividend = remainder << 32 | *limb as u64;
+            *limb = (dividend / divisor as u64) as u32;
+            remainder = dividend % divisor as u64;
+        }
+
+        self.trim();
+        return remainder as u32;
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(value: u64) -> BigUint {
+        let mut big = BigUint {
+            limbs: vec![value as u32, (value >> 32) as u32],
+        };
+
+        big.trim();
+        return big;
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &BigUint) -> Ordering {
+        // Without zero limbs at the top, a longer value is always bigger
+        return self.limbs.len().cmp(&other.limbs.len()).then_with(|| self.limbs.iter().rev().cmp(other.limbs.iter().rev()));
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const CHUNK_DIVISOR: u32 = 1_000_000_000;
+
+        // Split into base 10^9 chunks from least significant up
+        let mut remaining = self.clone();
+        let mut chunks = Vec::<u32>::new();
+
+        while !remaining.is_zero() {
+            chunks.push(remaining.div_rem_small(CHUNK_DIVISOR));
+        }
+
+        match chunks.pop() {
+            Some(top_chunk) => write!(f, "{}", top_chunk)?,
+            None => return write!(f, "0"),
+        }
+
+        for chunk in chunks.iter().rev() {
+            write!(f, "{:09}", chunk)?;
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn converts_u64() {
+        for value in [0, 1, u32::MAX as u64, u32::MAX as u64 + 1, u64::MAX] {
+            let big = BigUint::from(value);
+            assert_eq!(Some(value), big.to_u64());
+            assert_eq!(value.to_string(), big.to_string());
+            assert_eq!(64 - value.leading_zeros() as usize, big.bit_len());
+        }
+    }
+
+    #[test]
+    fn does_arithmetic_past_64_bits() {
+        let max = BigUint::from(u64::MAX);
+        let squared = max.mul(&max);
+
+        assert_eq!("340282366920938463426481119284349108225", squared.to_string());
+        assert_eq!("18446744073709551616", max.add(&BigUint::from(1)).to_string());
+        assert_eq!(None, squared.to_u64());
+        assert!(squared > max && max > BigUint::from(7));
+        assert_eq!(BigUint::zero(), max.mul(&BigUint::zero()));
+    }
+
+    #[test]
+    fn builds_from_nibbles() {
+        let mut big = BigUint::zero();
+
+        for _ in 0..20 {
+            big.push_nibble(0xF);
+        }
+
+        assert_eq!(80, big.bit_len());
+        assert_eq!(0xF, big.nibble(19));
+        assert_eq!(0, big.nibble(20));
+        assert_eq!(BigUint::from(u64::MAX).mul(&BigUint::from(1 << 16)).add(&BigUint::from(0xFFFF)), big);
+    }
+}
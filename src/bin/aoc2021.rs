@@ -0,0 +1,423 @@
+#![allow(clippy::needless_return)]
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use lvoytek_advent_of_code_2021::answers::{self, Answers, Verdict};
+use lvoytek_advent_of_code_2021::bench::{self, BenchResult};
+use lvoytek_advent_of_code_2021::{error, input};
+use lvoytek_advent_of_code_2021::runner::{self, RunResult, Solver};
+
+const USAGE: &str = "Usage: aoc2021 --day <N> [--part <1|2>] [--input <PATH|->] [--format <text|json>] [--verbose]
+       aoc2021 --all [--format <text|json>] [--verbose]
+       aoc2021 bench (--day <N> [--part <1|2>] [--input <PATH|->] | --all) [--iterations <N>]
+       aoc2021 verify [--day <N> [--part <1|2>]] [--answers <PATH>] [--record]
+       aoc2021 input list
+       aoc2021 input import <FILE> --day <N>
+       aoc2021 input import <DIR>
+       aoc2021 input fetch (--day <N> | --all)
+
+Inputs are cached as dayNN.txt in files/, or in $AOC_INPUT_DIR when it is set
+Missing inputs are fetched when $AOC_SESSION holds a session token, from $AOC_BASE_URL if set
+Importing a directory copies every file named dayNN.txt or after an older input name such as sonar_input
+JSON output is one object per line: {day, part, answer, elapsed_ms, input}, with error set when there is no answer
+Verbose output goes to stderr
+bench times parsing and solving separately, reporting min/median/max over the iterations (default 10)
+verify checks every answer against answers.toml in the input directory, --record saves any that are missing";
+
+#[derive(PartialEq)]
+enum Mode {
+    Run,
+    Bench,
+    Verify,
+}
+
+#[derive(PartialEq)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("Error, {}", message);
+    eprintln!("{}", USAGE);
+    process::exit(2);
+}
+
+fn parse_number_arg(flag: &str, value: Option<String>) -> u32 {
+    match value {
+        Some(value_str) => match value_str.parse::<u32>() {
+            Ok(value) => value,
+            Err(_) => exit_with_usage(&format!("{} expects a number, got \"{}\"", flag, value_str)),
+        },
+        None => exit_with_usage(&format!("{} expects a value", flag)),
+    }
+}
+
+fn print_text_row(solver: &Solver, result: &RunResult) {
+    let elapsed_ms = result.elapsed.as_secs_f64() * 1000.0;
+
+    match &result.answer {
+        Ok(answer) => {
+            // Multi-line answers (such as day 13's letters) are printed below their row
+            let mut answer_lines = answer.lines();
+            let first_line = if answer.contains('\n') {""} else {answer_lines.next().unwrap_or("")};
+
+            println!("{:>3}  {:>4}  {:<20}  {:>10.3}  {}", solver.day, solver.part, first_line, elapsed_ms, result.input);
+
+            for line in answer_lines {
+                println!("      {}", line);
+            }
+        },
+        Err(err) => println!("{:>3}  {:>4}  {:<20}  {:>10.3}  {}", solver.day, solver.part, err, elapsed_ms, result.input),
+    }
+}
+
+fn print_bench_rows(solver: &Solver, result: &BenchResult) {
+    let times = match &result.times {
+        Ok(times) => times,
+        Err(err) => {
+            println!("{:>3}  {:>4}  {}  {}", solver.day, solver.part, err, result.input);
+            return;
+        },
+    };
+
+    let phases = [("parse", &times.parse), ("solve", &times.solve)];
+
+    for (phase, phase_times) in phases {
+        let summary = bench::summarize(phase_times);
+
+        println!("{:>3}  {:>4}  {:<5}  {:>10.3}  {:>11.3}  {:>10.3}  {}", solver.day, solver.part, phase,
+                 summary.min.as_secs_f64() * 1000.0, summary.median.as_secs_f64() * 1000.0, summary.max.as_secs_f64() * 1000.0, result.input);
+    }
+}
+
+// Shorten answers spanning several lines to fit in a row
+fn answer_summary(answer: &str) -> String {
+    if answer.contains('\n') {
+        return format!("<{} lines>", answer.lines().count());
+    }
+
+    return answer.to_string();
+}
+
+fn print_verify_row(solver: &Solver, result: &RunResult, verdict: &Verdict) {
+    let answer = result.answer.as_deref().map_or(String::new(), answer_summary);
+
+    let (status, detail) = match verdict {
+        Verdict::Pass => ("pass", answer),
+        Verdict::Fail(expected) => ("FAIL", format!("{} (expected {})", answer, answer_summary(expected))),
+        Verdict::Missing => ("missing", answer),
+        Verdict::Error(err) => ("error", err.clone()),
+        Verdict::Skipped => ("skipped", "no input".to_string()),
+    };
+
+    println!("{:>3}  {:>4}  {:<7}  {}", solver.day, solver.part, status, detail);
+}
+
+fn load_answers(answers_path: &Path) -> Answers {
+    match fs::read_to_string(answers_path) {
+        Ok(contents) => return Answers::parse(&contents)
+            .unwrap_or_else(|err| error::report_and_exit(format!("Unable to read answers from {}: {}", answers_path.display(), err))),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Answers::new(),
+        Err(err) => error::report_and_exit(format!("Unable to read answers from {}: {}", answers_path.display(), err)),
+    }
+}
+
+// Run every given solver against the stored answers, exiting with an error if any are wrong or fail
+// Solvers without an input, such as days missing from a directory of examples, are skipped
+fn run_verify(solvers: &[&Solver], answers_path: &Path, record: bool, verbose: bool) {
+    let mut answers = load_answers(answers_path);
+    let (mut passed, mut failed, mut missing, mut skipped, mut errors) = (0, 0, 0, 0, 0);
+    let mut recorded = 0;
+
+    println!("{:>3}  {:>4}  {:<7}  Answer", "Day", "Part", "Result");
+
+    for solver in solvers {
+        if verbose {
+            eprintln!("Verifying day {} part {} ({})", solver.day, solver.part, solver.name);
+        }
+
+        let result = runner::run_solver(solver, None);
+        let verdict = if result.input_missing {Verdict::Skipped} else {answers.check(solver.day, solver.part, &result.answer)};
+
+        match &verdict {
+            Verdict::Pass => passed += 1,
+            Verdict::Fail(_) => failed += 1,
+            Verdict::Missing => missing += 1,
+            Verdict::Error(_) => errors += 1,
+            Verdict::Skipped => skipped += 1,
+        }
+
+        print_verify_row(solver, &result, &verdict);
+
+        if record && verdict == Verdict::Missing {
+            if let Ok(answer) = result.answer {
+                answers.insert(solver.day, solver.part, answer);
+                recorded += 1;
+            }
+        }
+    }
+
+    println!("{} passed, {} failed, {} missing, {} skipped, {} errors", passed, failed, missing, skipped, errors);
+
+    if recorded > 0 {
+        fs::write(answers_path, answers.to_toml())
+            .unwrap_or_else(|err| error::report_and_exit(format!("Unable to write answers to {}: {}", answers_path.display(), err)));
+        println!("Recorded {} answers in {}", recorded, answers_path.display());
+    }
+
+    if failed > 0 || errors > 0 {
+        process::exit(1);
+    }
+}
+
+// Days that have at least one solver, in order
+fn solved_days() -> Vec<u32> {
+    let mut days = runner::SOLVERS.iter().map(|solver| solver.day).collect::<Vec<u32>>();
+    days.dedup();
+    return days;
+}
+
+fn run_input_command(mut args: impl Iterator<Item = String>) {
+    let command = args.next();
+    let mut day: Option<u32> = None;
+    let mut fetch_all = false;
+    let mut path: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number_arg("--day", args.next())),
+            "--all" | "-a" => fetch_all = true,
+            _ if path.is_none() && !arg.starts_with('-') => path = Some(arg),
+            _ => exit_with_usage(&format!("unknown argument \"{}\"", arg)),
+        }
+    }
+
+    if day.is_some_and(|d| !(1..=25).contains(&d)) {
+        exit_with_usage("--day must be between 1 and 25");
+    }
+
+    let cache_dir = input::cache_dir();
+
+    match command.as_deref() {
+        Some("list") => {
+            for day in 1..=25 {
+                let cache_path = cache_dir.join(input::day_file_name(day));
+
+                if cache_path.exists() {
+                    println!("{:>3}  {}", day, cache_path.display());
+                }
+            }
+        },
+        Some("import") => {
+            let source_path = match &path {
+                Some(source_path) => Path::new(source_path),
+                None => exit_with_usage("import expects a file or directory"),
+            };
+
+            if source_path.is_dir() {
+                let days = input::import_dir(source_path, &cache_dir)
+                    .unwrap_or_else(|err| error::report_and_exit(format!("Unable to import {}: {}", source_path.display(), err)));
+
+                for day in days {
+                    println!("Imported day {} into {}", day, cache_dir.join(input::day_file_name(day)).display());
+                }
+            }
+            else {
+                let day = match day.or_else(|| source_path.file_name()?.to_str().and_then(input::day_from_file_name)) {
+                    Some(day) => day,
+                    None => exit_with_usage("importing a file needs --day unless it is named after one"),
+                };
+
+                let cache_path = input::import_file(source_path, day, &cache_dir)
+                    .unwrap_or_else(|err| error::report_and_exit(format!("Unable to import {}: {}", source_path.display(), err)));
+                println!("Imported day {} into {}", day, cache_path.display());
+            }
+        },
+        Some("fetch") => {
+            let config = match input::fetch_config_from_env() {
+                Some(config) => config,
+                None => error::report_and_exit(format!("fetching needs a session token in {}", input::SESSION_VAR)),
+            };
+
+            let days = match (day, fetch_all) {
+                (Some(day), false) => vec![day],
+                (None, true) => solved_days(),
+                _ => exit_with_usage("fetch expects either --day or --all"),
+            };
+
+            for day in days {
+                let cache_path = cache_dir.join(input::day_file_name(day));
+
+                input::fetch_into_cache(day, &cache_path, &config)
+                    .unwrap_or_else(|err| error::report_and_exit(format!("Unable to fetch day {}: {}", day, err)));
+                println!("Fetched day {} into {}", day, cache_path.display());
+            }
+        },
+        _ => exit_with_usage("input expects list, import or fetch"),
+    }
+}
+
+fn main() {
+    let mut args = env::args().skip(1).peekable();
+
+    if args.peek().is_some_and(|arg| arg == "input") {
+        args.next();
+        run_input_command(args);
+        return;
+    }
+
+    let mode = match args.peek().map(|arg| arg.as_str()) {
+        Some("bench") => Mode::Bench,
+        Some("verify") => Mode::Verify,
+        _ => Mode::Run,
+    };
+
+    if mode != Mode::Run {
+        args.next();
+    }
+
+    let mut day: Option<u32> = None;
+    let mut part: Option<u32> = None;
+    let mut run_all = false;
+    let mut input_path: Option<String> = None;
+    let mut format = OutputFormat::Text;
+    let mut verbose = false;
+    let mut iterations: Option<u32> = None;
+    let mut answers_path: Option<PathBuf> = None;
+    let mut record = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--day" | "-d" => day = Some(parse_number_arg("--day", args.next())),
+            "--part" | "-p" => part = Some(parse_number_arg("--part", args.next())),
+            "--all" | "-a" => run_all = true,
+            "--input" | "-i" => match args.next() {
+                Some(path) => input_path = Some(path),
+                None => exit_with_usage("--input expects a path"),
+            },
+            "--format" | "-f" => match args.next().as_deref() {
+                Some("text") => format = OutputFormat::Text,
+                Some("json") => format = OutputFormat::Json,
+                _ => exit_with_usage("--format expects text or json"),
+            },
+            "--verbose" | "-v" => verbose = true,
+            "--iterations" | "-n" => iterations = Some(parse_number_arg("--iterations", args.next())),
+            "--answers" => match args.next() {
+                Some(path) => answers_path = Some(PathBuf::from(path)),
+                None => exit_with_usage("--answers expects a path"),
+            },
+            "--record" => record = true,
+            "--help" | "-h" => {
+                println!("{}", USAGE);
+                return;
+            },
+            _ => exit_with_usage(&format!("unknown argument \"{}\"", arg)),
+        }
+    }
+
+    if run_all && (day.is_some() || part.is_some() || input_path.is_some()) {
+        exit_with_usage("--all cannot be combined with --day, --part or --input");
+    }
+
+    if !run_all && day.is_none() && mode != Mode::Verify {
+        exit_with_usage("either --day or --all is required");
+    }
+
+    if part.is_some_and(|p| p != 1 && p != 2) {
+        exit_with_usage("--part must be 1 or 2");
+    }
+
+    if iterations.is_some() && mode != Mode::Bench {
+        exit_with_usage("--iterations can only be used with bench");
+    }
+
+    if (answers_path.is_some() || record) && mode != Mode::Verify {
+        exit_with_usage("--answers and --record can only be used with verify");
+    }
+
+    if mode == Mode::Verify && (input_path.is_some() || format != OutputFormat::Text) {
+        exit_with_usage("verify reads the input directory's answers and only supports text output");
+    }
+
+    if iterations == Some(0) {
+        exit_with_usage("--iterations must be at least 1");
+    }
+
+    let solvers = runner::find_solvers(day, part);
+
+    if solvers.is_empty() {
+        eprintln!("Error, no solution for day {} part {}", day.unwrap_or(0), part.map_or("1 or 2".to_string(), |p| p.to_string()));
+        process::exit(1);
+    }
+
+    if mode == Mode::Verify {
+        let answers_path = answers_path.unwrap_or_else(|| input::cache_dir().join(answers::ANSWERS_FILE_NAME));
+        run_verify(&solvers, &answers_path, record, verbose);
+        return;
+    }
+
+    let mut any_failed = false;
+
+    if mode == Mode::Bench {
+        if format != OutputFormat::Text {
+            exit_with_usage("bench only supports text output");
+        }
+
+        let iterations = iterations.unwrap_or(bench::DEFAULT_ITERATIONS);
+        println!("{:>3}  {:>4}  {:<5}  {:>10}  {:>11}  {:>10}  Input", "Day", "Part", "Phase", "Min (ms)", "Median (ms)", "Max (ms)");
+
+        for solver in solvers {
+            if verbose {
+                eprintln!("Benchmarking day {} part {} ({}) over {} iterations", solver.day, solver.part, solver.name, iterations);
+            }
+
+            let result = bench::run_bench(solver, input_path.as_deref(), iterations);
+
+            if result.times.is_err() {
+                any_failed = true;
+            }
+
+            print_bench_rows(solver, &result);
+        }
+
+        if any_failed {
+            process::exit(1);
+        }
+
+        return;
+    }
+
+    if format == OutputFormat::Text {
+        println!("{:>3}  {:>4}  {:<20}  {:>10}  Input", "Day", "Part", "Answer", "Time (ms)");
+    }
+
+    for solver in solvers {
+        if verbose {
+            eprintln!("Running day {} part {} ({})", solver.day, solver.part, solver.name);
+        }
+
+        let result = runner::run_solver(solver, input_path.as_deref());
+
+        if verbose {
+            eprintln!("Finished day {} part {} from {} in {:?}", solver.day, solver.part, result.input, result.elapsed);
+        }
+
+        if result.answer.is_err() {
+            any_failed = true;
+        }
+
+        match format {
+            OutputFormat::Text => print_text_row(solver, &result),
+            OutputFormat::Json => println!("{}", runner::result_to_json(solver, &result)),
+        }
+    }
+
+    if any_failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,78 @@
+// Day 1: Sonar Sweep
+
+use crate::error::{self, AocResult};
+
+pub fn parse_depths(input: &str) -> AocResult<Vec<i32>> {
+    let mut values = Vec::new();
+
+    for (line_num, line) in error::numbered_lines(input) {
+        values.push(error::parse_token::<i32>(line, line, line_num)?);
+    }
+
+    return Ok(values);
+}
+
+pub fn count_increases(depths: &[i32]) -> u32 {
+    let mut larger_count = 0;
+    let mut prev_value = i32::MAX;
+
+    for &int_value in depths {
+        if int_value > prev_value {
+            larger_count += 1;
+        }
+
+        prev_value = int_value;
+    }
+
+    return larger_count;
+}
+
+pub fn count_window_increases(depths: &[i32]) -> u32 {
+    let mut larger_count = 0;
+    let mut prev_sum = i32::MAX;
+
+    for i in 0..depths.len().saturating_sub(2) {
+        let new_sum = depths[i] + depths[i+1] + depths[i+2];
+
+        if new_sum > prev_sum {
+            larger_count += 1;
+        }
+
+        prev_sum = new_sum;
+    }
+
+    return larger_count;
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    return Ok(count_increases(&parse_depths(input)?));
+}
+
+pub fn part2(input: &str) -> AocResult<u32> {
+    return Ok(count_window_increases(&parse_depths(input)?));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AocError;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day01.txt");
+
+    #[test]
+    fn counts_increases() {
+        let depths = parse_depths(EXAMPLE).unwrap();
+        assert_eq!(7, count_increases(&depths));
+        assert_eq!(5, count_window_increases(&depths));
+    }
+
+    #[test]
+    fn short_input_has_no_window_increases() {
+        assert_eq!(0, count_window_increases(&[1, 2]));
+    }
+
+    #[test]
+    fn reports_bad_depth() {
+        assert_eq!(AocError::parse(2, 1, "invalid number \"2x0\""), parse_depths("199\n2x0\n").unwrap_err());
+    }
+}
@@ -0,0 +1,194 @@
+// Day 10: Syntax Scoring
+
+use crate::error::{self, AocError, AocResult};
+
+pub fn get_bad_syntax_score(bad_char: char) -> u32 {
+    match bad_char {
+        ')' => return 3,
+        ']' => return 57,
+        '}' => return 1197,
+        '>' => return 25137,
+        _ => return 0,
+    }
+}
+
+pub fn get_autocomplete_syntax_score(bad_char: char) -> u64 {
+    match bad_char {
+        ')' => return 1,
+        ']' => return 2,
+        '}' => return 3,
+        '>' => return 4,
+        _ => return 0,
+    }
+}
+
+pub fn get_matching_char(open_char: char) -> char {
+    match open_char {
+        '(' => return ')',
+        '[' => return ']',
+        '{' => return '}',
+        '<' => return '>',
+        _ => return ':',
+    }
+}
+
+pub fn does_char_match(open_char: char, close_char: char) -> bool {
+    return close_char == get_matching_char(open_char);
+}
+
+pub fn is_opening(input_char: char) -> bool {
+    match input_char {
+        '(' | '[' | '{' | '<' => return true,
+        _ => return false
+    }
+}
+
+pub fn is_closing(input_char: char) -> bool {
+    match input_char {
+        ')' | ']' | '}' | '>' => return true,
+        _ => return false
+    }
+}
+
+// Make sure a line only contains chunk characters
+pub fn check_line_chars(line: &str, line_num: usize) -> AocResult<()> {
+    for (char_index, next_char) in line.chars().enumerate() {
+        if !is_opening(next_char) && !is_closing(next_char) {
+            return Err(AocError::parse(line_num, char_index + 1, format!("bad chunk character '{}'", next_char)));
+        }
+    }
+
+    return Ok(());
+}
+
+// Get the score of the first illegal character in a line, 0 if there is none
+pub fn get_corrupted_score(line: &str) -> u32 {
+    // Create a stack for opening chars and add a fail char to the bottom
+    let mut chunk_stack = Vec::<char>::new();
+    chunk_stack.push(':');
+
+    for next_char in line.chars() {
+        if is_opening(next_char) {
+            chunk_stack.push(next_char);
+        }
+        else {
+            let stack_index = chunk_stack.len() - 1;
+            if does_char_match(chunk_stack[stack_index], next_char) {
+                chunk_stack.pop();
+            }
+            else {
+                return get_bad_syntax_score(next_char);
+            }
+        }
+    }
+
+    return 0;
+}
+
+// Get the autocomplete score for an incomplete line, None if the line is corrupted
+pub fn get_autocomplete_score(line: &str) -> Option<u64> {
+    // Create a stack for opening chars and add a fail char to the bottom
+    let mut chunk_stack = Vec::<char>::new();
+    chunk_stack.push(':');
+
+    for next_char in line.chars() {
+        if is_opening(next_char) {
+            chunk_stack.push(next_char);
+        }
+        else {
+            let stack_index = chunk_stack.len() - 1;
+            if does_char_match(chunk_stack[stack_index], next_char) {
+                chunk_stack.pop();
+            }
+            else {
+                // Bad line, ignore and move on
+                return None;
+            }
+        }
+    }
+
+    let mut current_score: u64 = 0;
+
+    // flip stack and remove invalid character :
+    chunk_stack.reverse();
+    chunk_stack.pop();
+
+    for open_char in chunk_stack {
+        current_score *= 5;
+        current_score += get_autocomplete_syntax_score(get_matching_char(open_char));
+    }
+
+    return Some(current_score);
+}
+
+// Get every line, making sure each only contains chunk characters
+pub fn parse_chunk_lines(input: &str) -> AocResult<Vec<&str>> {
+    let mut lines = Vec::<&str>::new();
+
+    for (line_num, line) in error::numbered_lines(input) {
+        check_line_chars(line, line_num)?;
+        lines.push(line);
+    }
+
+    return Ok(lines);
+}
+
+pub fn get_total_corrupted_score(lines: &[&str]) -> u32 {
+    let mut syntax_score = 0;
+
+    for line in lines {
+        syntax_score += get_corrupted_score(line);
+    }
+
+    return syntax_score;
+}
+
+// Get the middle autocomplete score of all the incomplete lines
+pub fn get_middle_autocomplete_score(lines: &[&str]) -> AocResult<u64> {
+    let mut syntax_scores = Vec::<u64>::new();
+
+    for line in lines {
+        if let Some(score) = get_autocomplete_score(line) {
+            syntax_scores.push(score);
+        }
+    }
+
+    if syntax_scores.is_empty() {
+        return Err(AocError::NoSolution("no incomplete lines".to_string()));
+    }
+
+    // Find middle syntax score
+    syntax_scores.sort();
+    let middle_score_index = syntax_scores.len() / 2;
+    return Ok(syntax_scores[middle_score_index]);
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    return Ok(get_total_corrupted_score(&parse_chunk_lines(input)?));
+}
+
+pub fn part2(input: &str) -> AocResult<u64> {
+    return get_middle_autocomplete_score(&parse_chunk_lines(input)?);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scores_corrupted_lines() {
+        assert_eq!(1197, get_corrupted_score("{([(<{}[<>[]}>{[]{[(<()>"));
+        assert_eq!(0, get_corrupted_score("[({(<(())[]>[[{[]{<()<>>"));
+    }
+
+    #[test]
+    fn scores_incomplete_lines() {
+        assert_eq!(Some(288957), get_autocomplete_score("[({(<(())[]>[[{[]{<()<>>"));
+        assert_eq!(None, get_autocomplete_score("{([(<{}[<>[]}>{[]{[(<()>"));
+    }
+
+    #[test]
+    fn rejects_bad_characters() {
+        assert_eq!(AocError::parse(4, 3, "bad chunk character 'a'"), check_line_chars("[(a)]", 4).unwrap_err());
+    }
+}
@@ -0,0 +1,121 @@
+// Day 11: Dumbo Octopus
+
+use crate::error::AocResult;
+use crate::grid::Grid;
+
+pub fn build_octomap(input: &str) -> AocResult<Grid<u32>> {
+    return Grid::parse_digits(input, "energy level");
+}
+
+pub fn get_flashes(x: usize, y: usize, octomap: &mut Grid<u32>) -> u32 {
+    // Check if current octopus should flash, otherwise exit early
+    if octomap[(x, y)] > 9 {
+        octomap[(x, y)] = 0;
+
+        let mut flash_count = 1;
+
+        // Increase adjacent values if they have not just flashed
+        for neighbor in octomap.neighbors8(x, y) {
+            if octomap[neighbor] > 0 {
+                octomap[neighbor] += 1;
+            }
+        }
+
+        // Recurse into adjacent areas
+        for neighbor in octomap.neighbors8(x, y) {
+            flash_count += get_flashes(neighbor.0, neighbor.1, octomap);
+        }
+
+        return flash_count;
+    }
+
+    return 0;
+}
+
+// Simulate a single step and return the number of flashes in it
+pub fn step(octomap: &mut Grid<u32>) -> u32 {
+    let mut flash_count = 0;
+
+    // Increase all values by 1
+    for y in 0..octomap.height() {
+        for x in 0..octomap.width() {
+            octomap[(x, y)] += 1;
+        }
+    }
+
+    // Go through flashes
+    for y in 0..octomap.height() {
+        for x in 0..octomap.width() {
+            flash_count += get_flashes(x, y, octomap);
+        }
+    }
+
+    return flash_count;
+}
+
+pub fn is_in_sync(octomap: &Grid<u32>) -> bool {
+    return octomap.iter().all(|&energy| energy == 0);
+}
+
+// Simulate the given number of steps and return the total number of flashes
+pub fn count_flashes(octomap: &mut Grid<u32>, num_steps: u32) -> u32 {
+    let mut flash_count = 0;
+
+    for _ in 0..num_steps {
+        flash_count += step(octomap);
+    }
+
+    return flash_count;
+}
+
+// Simulate until a sync happens and return the step it happened on
+pub fn find_sync_step(octomap: &mut Grid<u32>) -> u32 {
+    let mut sync_timer = 0;
+
+    loop {
+        step(octomap);
+        sync_timer += 1;
+
+        if is_in_sync(octomap) {
+            return sync_timer;
+        }
+    }
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    return Ok(count_flashes(&mut build_octomap(input)?, 100));
+}
+
+pub fn part2(input: &str) -> AocResult<u32> {
+    return Ok(find_sync_step(&mut build_octomap(input)?));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day11.txt");
+
+    #[test]
+    fn steps_small_grid() {
+        let mut octomap = build_octomap("11111\n19991\n19191\n19991\n11111\n").unwrap();
+
+        assert_eq!(9, step(&mut octomap));
+        assert_eq!(&[3, 4, 5, 4, 3], octomap.row(0));
+        assert_eq!(&[4, 0, 0, 0, 4], octomap.row(1));
+        assert_eq!(0, step(&mut octomap));
+    }
+
+    #[test]
+    fn syncs() {
+        let mut octomap = build_octomap(EXAMPLE).unwrap();
+
+        for _ in 0..194 {
+            step(&mut octomap);
+            assert!(!is_in_sync(&octomap));
+        }
+
+        step(&mut octomap);
+        assert!(is_in_sync(&octomap));
+    }
+}
@@ -0,0 +1,222 @@
+// Day 12: Passage Pathing
+
+use std::fs;
+use std::io;
+
+use crate::error::{self, AocError, AocResult};
+
+#[derive(Clone)]
+pub struct Cave<'a> {
+    pub index: usize,
+    pub name: &'a str,
+    pub is_repeatable: bool,
+    pub marked: bool,
+    pub connections: Vec<usize>,
+}
+
+// Create a dot file for visualizing the cave system
+pub fn dot_builder(mut caves: Vec<Cave>) -> io::Result<()> {
+    let mut output_data: String = "graph caves {\n".to_string();
+
+    // Create nodes
+    for cave in &caves {
+        output_data.push_str(&cave.index.to_string());
+        output_data.push_str(" [label=\"");
+        output_data.push_str(cave.name);
+        output_data.push('"');
+
+        if cave.is_repeatable {
+            output_data.push_str(" shape=box");
+        }
+
+        output_data.push_str("];\n");
+    }
+
+    // Add edges
+    for i in 0..caves.len() {
+        caves[i].marked = true;
+
+        for connection in caves[i].connections.clone() {
+            if !caves[connection].marked {
+                output_data.push_str(&i.to_string());
+                output_data.push_str(" -- ");
+                output_data.push_str(&connection.to_string());
+                output_data.push_str(";\n");
+            }
+        }
+    }
+
+    output_data.push('}');
+
+    return fs::write("caves.dot", output_data);
+}
+
+// Build up the cave graph, returning it and the index of the start cave
+pub fn build_caves(input: &str) -> AocResult<(Vec<Cave<'_>>, usize)> {
+    // Build up the graph
+    let mut caves = Vec::<Cave>::new();
+    let mut start_index: Option<usize> = None;
+
+    for (line_num, line) in error::numbered_lines(input) {
+        let line_node_names = line.split("-").collect::<Vec<&str>>();
+        let mut node_indecies = Vec::<usize>::new();
+
+        if line_node_names.len() != 2 {
+            return Err(AocError::parse(line_num, 1, "expected a connection as cave-cave"));
+        }
+
+        for node in line_node_names {
+            if node.is_empty() || !node.chars().all(|c| c.is_ascii_alphabetic()) {
+                return Err(AocError::parse(line_num, error::column_of(line, node), format!("bad cave name \"{}\"", node)));
+            }
+
+            let mut already_added = false;
+            let mut cave_index : usize = 0;
+
+            for cave in &caves {
+                if cave.name == node {
+                    cave_index = cave.index;
+                    already_added = true;
+                }
+            }
+
+            if !already_added {
+                cave_index = caves.len();
+
+                let is_lower = node == node.to_lowercase();
+
+                caves.push(Cave {
+                    index: cave_index,
+                    name: node,
+                    is_repeatable: !is_lower,
+                    marked: false,
+                    connections: Vec::<usize>::new(),
+                });
+
+                // Note the start cave index
+                if node == "start" {
+                    start_index = Some(cave_index);
+                }
+            }
+
+            node_indecies.push(cave_index);
+        }
+
+        // Two connected big caves could be bounced between forever
+        if caves[node_indecies[0]].is_repeatable && caves[node_indecies[1]].is_repeatable {
+            return Err(AocError::parse(line_num, 1, "big caves cannot be connected to each other"));
+        }
+
+        // Add connections
+        caves[node_indecies[0]].connections.push(node_indecies[1]);
+        caves[node_indecies[1]].connections.push(node_indecies[0]);
+    }
+
+    if !caves.iter().any(|cave| cave.name == "end") {
+        return Err(AocError::Input("no end cave".to_string()));
+    }
+
+    match start_index {
+        Some(start_index) => return Ok((caves, start_index)),
+        None => return Err(AocError::Input("no start cave".to_string())),
+    }
+}
+
+// Recursively run through the cave paths, assuming no two adjacent re-traversable caves
+pub fn get_num_paths_to_end(cave_index: usize, caves: &mut [Cave]) -> u32 {
+    // This is the end node, return 1 for num paths
+    if caves[cave_index].name == "end" {
+        return 1;
+    }
+
+    let mut num_paths = 0;
+
+    // Mark this cave as traversed
+    caves[cave_index].marked = true;
+
+    // Recurse into every unmarked / re-traversable cave
+    for connection in caves[cave_index].connections.clone() {
+        if !caves[connection].marked || caves[connection].is_repeatable {
+            num_paths += get_num_paths_to_end(connection, caves);
+        }
+    }
+
+    // Unmark this cave for future traversal
+    caves[cave_index].marked = false;
+
+    return num_paths;
+}
+
+// Recursively run through the cave paths, assuming no two adjacent re-traversable caves
+// other than one once retraversable small cave
+pub fn get_num_paths_to_end_with_repeat(cave_index: usize, caves: &mut [Cave], already_retraversed: bool, is_new_retraverse: bool) -> u32 {
+    // This is the end node, return 1 for num paths
+    if caves[cave_index].name == "end" {
+        return 1;
+    }
+
+    let mut num_paths = 0;
+
+    // Mark this cave as traversed
+    caves[cave_index].marked = true;
+
+    // Recurse into every unmarked / re-traversable cave
+    for connection in caves[cave_index].connections.clone() {
+        if !caves[connection].marked || caves[connection].is_repeatable {
+            num_paths += get_num_paths_to_end_with_repeat(connection, caves, already_retraversed, false);
+        }
+
+        if !already_retraversed && !caves[connection].is_repeatable && caves[connection].marked &&
+            caves[connection].name != "start" {
+            num_paths += get_num_paths_to_end_with_repeat(connection, caves, true, true);
+        }
+    }
+
+    // Unmark this cave for future traversal if other paths found
+    if !is_new_retraverse {
+        caves[cave_index].marked = false;
+    }
+
+    return num_paths;
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    let (mut caves, start_index) = build_caves(input)?;
+
+    // Starting at start, traverse every path
+    return Ok(get_num_paths_to_end(start_index, &mut caves));
+}
+
+pub fn part2(input: &str) -> AocResult<u32> {
+    let (mut caves, start_index) = build_caves(input)?;
+
+    // Starting at start, traverse every path
+    return Ok(get_num_paths_to_end_with_repeat(start_index, &mut caves, false, false));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day12.txt");
+
+    const LARGER_EXAMPLE: &str = "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc\n";
+
+    #[test]
+    fn counts_paths() {
+        let (mut caves, start_index) = build_caves(EXAMPLE).unwrap();
+        assert_eq!(10, get_num_paths_to_end(start_index, &mut caves));
+        assert_eq!(36, get_num_paths_to_end_with_repeat(start_index, &mut caves, false, false));
+
+        let (mut caves, start_index) = build_caves(LARGER_EXAMPLE).unwrap();
+        assert_eq!(19, get_num_paths_to_end(start_index, &mut caves));
+        assert_eq!(103, get_num_paths_to_end_with_repeat(start_index, &mut caves, false, false));
+    }
+
+    #[test]
+    fn rejects_bad_cave_systems() {
+        assert!(matches!(build_caves("start-A\nA-end\nA-B\n"), Err(AocError::Parse { line: 3, .. })));
+        assert!(matches!(build_caves("start-a\n"), Err(AocError::Input(_))));
+        assert!(matches!(build_caves("start-a-end\n"), Err(AocError::Parse { line: 1, .. })));
+    }
+}
@@ -0,0 +1,179 @@
+// Day 13: Transparent Origami
+
+use crate::error::{self, AocError, AocResult};
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Copy, Clone)]
+pub struct Fold {
+    pub is_horizontal: bool,
+    pub value: i32,
+}
+
+// Extract points and folds
+pub fn parse_origami(input: &str) -> AocResult<(Vec<Point>, Vec<Fold>)> {
+    let mut points = Vec::<Point>::new();
+    let mut folds = Vec::<Fold>::new();
+
+    let mut point_grab = true;
+
+    for (line_num, line) in error::numbered_lines(input) {
+        if line.is_empty() {
+            point_grab = false;
+        }
+        else if point_grab {
+            let point_vec = line.split(',').collect::<Vec<&str>>();
+
+            if point_vec.len() != 2 {
+                return Err(AocError::parse(line_num, 1, "expected a point as x,y"));
+            }
+
+            points.push(Point {
+                x: error::parse_token::<i32>(point_vec[0], line, line_num)?,
+                y: error::parse_token::<i32>(point_vec[1], line, line_num)?,
+            });
+        }
+        else {
+            let fold_str_vec = line.split_whitespace().collect::<Vec<&str>>();
+
+            if fold_str_vec.len() != 3 || fold_str_vec[0] != "fold" || fold_str_vec[1] != "along" {
+                return Err(AocError::parse(line_num, 1, "expected a fold as fold along x=n or fold along y=n"));
+            }
+
+            let fold_vec = fold_str_vec[2].split('=').collect::<Vec<&str>>();
+
+            if fold_vec.len() != 2 || (fold_vec[0] != "x" && fold_vec[0] != "y") {
+                return Err(AocError::parse(line_num, error::column_of(line, fold_str_vec[2]), format!("bad fold \"{}\"", fold_str_vec[2])));
+            }
+
+            folds.push(Fold {
+                is_horizontal: fold_vec[0] == "y",
+                value: error::parse_token::<i32>(fold_vec[1], line, line_num)?,
+            });
+        }
+    }
+
+    if folds.is_empty() {
+        return Err(AocError::Input("no folds given".to_string()));
+    }
+
+    return Ok((points, folds));
+}
+
+pub fn fold_points(fold: &Fold, points: &mut Vec<Point>) {
+    // Fold
+    for point_index in 0..points.len() {
+        if fold.is_horizontal {
+            if points[point_index].y > fold.value {
+                let fold_offset = 2 * (points[point_index].y - fold.value);
+                points[point_index].y -= fold_offset;
+            }
+        }
+        else if points[point_index].x > fold.value {
+            let fold_offset = 2 * (points[point_index].x - fold.value);
+            points[point_index].x -= fold_offset;
+        }
+    }
+
+    // Find and eliminate duplicates
+    for i in (0..points.len()).rev() {
+        for j in 0..i {
+            if points[i] == points[j] {
+                points.remove(i);
+                break;
+            }
+        }
+    }
+}
+
+// Draw the points as a grid of # characters
+pub fn render_points(points: &[Point]) -> String {
+    // Find largest x and y then draw grid based on that size
+    let mut largest_x = 0;
+    let mut largest_y = 0;
+
+    for point in points {
+        if point.x > largest_x {
+            largest_x = point.x;
+        }
+
+        if point.y > largest_y {
+            largest_y = point.y;
+        }
+    }
+
+    let mut output = String::new();
+
+    for y in 0..=largest_y {
+        for x in 0..=largest_x {
+            let mut point_exists = false;
+
+            for point in points {
+                if point.x == x && point.y == y {
+                    point_exists = true;
+                    break;
+                }
+            }
+
+            if point_exists {
+                output.push('#');
+            }
+            else {
+                output.push(' ');
+            }
+        }
+        output.push('\n');
+    }
+
+    return output;
+}
+
+pub fn part1(input: &str) -> AocResult<usize> {
+    let (mut points, folds) = parse_origami(input)?;
+
+    // Run through first fold only
+    let first_fold = folds.first().ok_or_else(|| AocError::NoSolution("no folds given".to_string()))?;
+    fold_points(first_fold, &mut points);
+
+    return Ok(points.len());
+}
+
+pub fn part2(input: &str) -> AocResult<String> {
+    let (mut points, folds) = parse_origami(input)?;
+
+    for fold in &folds {
+        fold_points(fold, &mut points);
+    }
+
+    return Ok(render_points(&points));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day13.txt");
+
+    #[test]
+    fn folds_points() {
+        let (mut points, folds) = parse_origami(EXAMPLE).unwrap();
+        assert_eq!(18, points.len());
+        assert_eq!(2, folds.len());
+
+        fold_points(&folds[0], &mut points);
+        assert_eq!(17, points.len());
+
+        fold_points(&folds[1], &mut points);
+        assert_eq!("#####\n#   #\n#   #\n#   #\n#####\n", render_points(&points));
+    }
+
+    #[test]
+    fn rejects_bad_folds() {
+        assert_eq!(AocError::parse(3, 12, "bad fold \"z=2\""), parse_origami("1,1\n\nfold along z=2\n").err().unwrap());
+        assert!(matches!(parse_origami("1,1\n"), Err(AocError::Input(_))));
+    }
+}
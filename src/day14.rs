@@ -0,0 +1,287 @@
+// Day 14: Extended Polymerization
+
+use std::cmp::Ordering;
+
+use crate::error::{self, AocError, AocResult};
+
+#[derive(Clone, Eq, PartialEq)]
+pub struct ElementPair {
+    pub front: char,
+    pub back: char,
+}
+
+#[derive(Clone)]
+pub struct Transfer {
+    pub pair: ElementPair,
+    pub insert: char,
+}
+
+#[derive(Clone)]
+pub struct ElementPairAmount {
+    pub pair: ElementPair,
+    pub amount: u128,
+}
+
+#[derive(Clone, Eq)]
+pub struct ElementAmount {
+    pub element: char,
+    pub amount: u128,
+}
+
+impl PartialOrd for ElementAmount {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ElementAmount {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.amount.cmp(&other.amount)
+    }
+}
+
+impl PartialEq for ElementAmount {
+    fn eq(&self, other: &Self) -> bool {
+        self.amount == other.amount
+    }
+}
+
+// Get the polymer template and the pair insertion rules
+pub fn parse_polymer(input: &str) -> AocResult<(String, Vec<Transfer>)> {
+    let lines = input.lines().collect::<Vec<&str>>();
+
+    if lines.is_empty() || lines[0].is_empty() {
+        return Err(AocError::Input("no polymer template".to_string()));
+    }
+
+    if let Some(offset) = lines[0].find(|c: char| !c.is_ascii_uppercase()) {
+        return Err(AocError::parse(1, offset + 1, "elements must be uppercase letters"));
+    }
+
+    let elements : String = lines[0].to_string();
+
+    if lines.len() > 1 && !lines[1].is_empty() {
+        return Err(AocError::parse(2, 1, "expected a blank line after the template"));
+    }
+
+    let mut transfers = Vec::<Transfer>::new();
+
+    for (line_num, line) in error::numbered_lines(input).skip(2) {
+        let from_insert = line.split(" -> ").collect::<Vec<&str>>();
+
+        if from_insert.len() != 2 {
+            return Err(AocError::parse(line_num, 1, "expected a rule as AB -> C"));
+        }
+
+        let pair_chars = from_insert[0].chars().collect::<Vec<char>>();
+        let insert_chars = from_insert[1].chars().collect::<Vec<char>>();
+
+        if pair_chars.len() != 2 || !pair_chars.iter().all(|c| c.is_ascii_uppercase()) {
+            return Err(AocError::parse(line_num, 1, format!("bad element pair \"{}\"", from_insert[0])));
+        }
+
+        if insert_chars.len() != 1 || !insert_chars[0].is_ascii_uppercase() {
+            return Err(AocError::parse(line_num, error::column_of(line, from_insert[1]), format!("bad inserted element \"{}\"", from_insert[1])));
+        }
+
+        transfers.push(Transfer {
+            pair: ElementPair {
+                front: pair_chars[0],
+                back: pair_chars[1],
+            },
+
+            insert: insert_chars[0],
+        });
+    }
+
+    return Ok((elements, transfers));
+}
+
+// Insert elements directly into the polymer string for a single step
+pub fn insert_elements(elements: &mut String, transfers: &[Transfer]) {
+    let mut i: usize = 0;
+    for _ in 0..elements.len() - 1 {
+        for transfer in transfers {
+            let element_bytes = elements.as_bytes();
+
+            if element_bytes[i] as char == transfer.pair.front && element_bytes[i+1] as char == transfer.pair.back {
+                elements.insert(i+1, transfer.insert);
+                i += 1;
+                break;
+            }
+        }
+        i += 1;
+    }
+}
+
+fn add_element_amount(element: char, count_buckets: &mut Vec<ElementAmount>, amount: u128) {
+    let mut element_found = false;
+
+    for i in 0..count_buckets.len() {
+        if element == count_buckets[i].element {
+            count_buckets[i].amount += amount;
+            element_found = true;
+        }
+    }
+
+    if !element_found {
+        count_buckets.push(ElementAmount {
+            element,
+            amount,
+        });
+    }
+}
+
+// Count each element in the polymer string, sorted from least to most common
+pub fn count_elements(elements: &str) -> Vec<ElementAmount> {
+    let mut count_buckets = Vec::<ElementAmount>::new();
+
+    for element in elements.chars() {
+        add_element_amount(element, &mut count_buckets, 1);
+    }
+
+    count_buckets.sort();
+    return count_buckets;
+}
+
+pub fn add_element_pair(new_pair: ElementPair, element_pair_counter: &mut Vec<ElementPairAmount>, amount: u128) {
+    let mut pair_already_exists = false;
+
+    for epair_index in 0..element_pair_counter.len() {
+        if element_pair_counter[epair_index].pair == new_pair {
+            element_pair_counter[epair_index].amount += amount;
+            pair_already_exists = true;
+            break;
+        }
+    }
+
+    if !pair_already_exists {
+        element_pair_counter.push(ElementPairAmount {
+            pair: new_pair,
+            amount,
+        });
+    }
+}
+
+// Count the pairs in the polymer after the given number of steps without building it
+pub fn count_pairs_after_steps(elements: &str, transfers: &[Transfer], num_steps: u32) -> Vec<ElementPairAmount> {
+    let mut element_pair_counter = Vec::<ElementPairAmount>::new();
+    let element_chars = elements.chars().collect::<Vec<char>>();
+
+    // Add initial element pairs
+    for i in 0..element_chars.len() - 1 {
+        let new_pair = ElementPair {
+            front: element_chars[i],
+            back: element_chars[i+1],
+        };
+
+        add_element_pair(new_pair, &mut element_pair_counter, 1);
+    }
+
+    for _ in 0..num_steps {
+        let mut new_pair_vec = Vec::<ElementPairAmount>::new();
+
+        for pair_count in &element_pair_counter {
+            for transfer in transfers {
+                if pair_count.pair == transfer.pair {
+                    let front_pair = ElementPair {
+                        front: transfer.pair.front,
+                        back: transfer.insert,
+                    };
+
+                    let back_pair = ElementPair {
+                        front: transfer.insert,
+                        back: transfer.pair.back,
+                    };
+
+                    add_element_pair(front_pair, &mut new_pair_vec, pair_count.amount);
+                    add_element_pair(back_pair, &mut new_pair_vec, pair_count.amount);
+                    break;
+                }
+            }
+        }
+
+        element_pair_counter = new_pair_vec;
+    }
+
+    return element_pair_counter;
+}
+
+// Count each element from the pair counts, sorted from least to most common
+pub fn count_elements_from_pairs(first_element: char, element_pair_counter: &[ElementPairAmount]) -> Vec<ElementAmount> {
+    let mut count_buckets = Vec::<ElementAmount>::new();
+
+    // Add elements by counting back of all pairs
+    for element_pair_count in element_pair_counter {
+        add_element_amount(element_pair_count.pair.back, &mut count_buckets, element_pair_count.amount);
+    }
+
+    // Add 1 to first element to account for it
+    add_element_amount(first_element, &mut count_buckets, 1);
+
+    count_buckets.sort();
+    return count_buckets;
+}
+
+pub fn part1(input: &str) -> AocResult<u128> {
+    let (mut elements, transfers) = parse_polymer(input)?;
+
+    // Run through 10 steps
+    for _ in 0..10 {
+        insert_elements(&mut elements, &transfers);
+    }
+
+    let count_buckets = count_elements(&elements);
+    return Ok(count_buckets[count_buckets.len() - 1].amount - count_buckets[0].amount);
+}
+
+pub fn part2(input: &str) -> AocResult<u128> {
+    let (elements, transfers) = parse_polymer(input)?;
+
+    // Run through 40 steps
+    let element_pair_counter = count_pairs_after_steps(&elements, &transfers, 40);
+
+    let count_buckets = count_elements_from_pairs(elements.as_bytes()[0] as char, &element_pair_counter);
+    return Ok(count_buckets[count_buckets.len() - 1].amount - count_buckets[0].amount);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day14.txt");
+
+    #[test]
+    fn inserts_elements() {
+        let (mut elements, transfers) = parse_polymer(EXAMPLE).unwrap();
+
+        insert_elements(&mut elements, &transfers);
+        assert_eq!("NCNBCHB", elements);
+
+        insert_elements(&mut elements, &transfers);
+        assert_eq!("NBCCNBBBCBHCB", elements);
+    }
+
+    #[test]
+    fn counts_pairs_like_insertion() {
+        let (mut elements, transfers) = parse_polymer(EXAMPLE).unwrap();
+        let element_pair_counter = count_pairs_after_steps(&elements, &transfers, 10);
+        let from_pairs = count_elements_from_pairs('N', &element_pair_counter);
+
+        for _ in 0..10 {
+            insert_elements(&mut elements, &transfers);
+        }
+
+        let from_string = count_elements(&elements);
+
+        assert_eq!(from_string.len(), from_pairs.len());
+        for i in 0..from_string.len() {
+            assert_eq!((from_string[i].element, from_string[i].amount), (from_pairs[i].element, from_pairs[i].amount));
+        }
+    }
+
+    #[test]
+    fn rejects_bad_rules() {
+        assert_eq!(AocError::parse(3, 1, "bad element pair \"CHH\""), parse_polymer("NN\n\nCHH -> B\n").err().unwrap());
+    }
+}
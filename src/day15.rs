@@ -0,0 +1,157 @@
+// Day 15: Chiton
+
+use crate::error::AocResult;
+use crate::grid::Grid;
+
+#[derive(Clone, Copy, Eq)]
+pub struct Pos {
+    pub x: usize,
+    pub y: usize,
+    pub cumulative_score: u32,
+    pub heuristic_distance: u32,
+}
+
+impl PartialEq for Pos {
+    fn eq(&self, other: &Self) -> bool {
+        self.x == other.x && self.y == other.y
+    }
+}
+
+// Get an estimated distance to the end
+pub fn get_heuristic_distance(x: usize, y: usize, risk_map: &Grid<u32>) -> u32 {
+    let final_x = risk_map.width() - 1;
+    let final_y = risk_map.height() - 1;
+
+    // Find basic manhattan distance to get heuristic
+    let x_remaining = (final_x - x) as u32;
+    let y_remaining = (final_y - y) as u32;
+
+    return x_remaining + y_remaining;
+}
+
+// Run A* from the top left to the bottom right and return the lowest total risk
+pub fn get_lowest_risk(risk_map: &Grid<u32>) -> u32 {
+    let width = risk_map.width();
+    let height = risk_map.height();
+
+    let mut open = Vec::<Pos>::new();
+    let mut closed = Grid::new(width, height, false);
+
+    // Start with start node
+    open.push(Pos {
+        x: 0,
+        y: 0,
+        cumulative_score: 0,
+        heuristic_distance: 0,
+    });
+
+    while !open.is_empty() {
+        // Find open position with lowest score
+        let mut current_pos_index : usize = 0;
+
+        for open_pos_index in 0..open.len() {
+            if open[open_pos_index].cumulative_score + open[open_pos_index].heuristic_distance <
+                open[current_pos_index].cumulative_score + open[current_pos_index].heuristic_distance {
+                current_pos_index = open_pos_index;
+            }
+        }
+
+        let current_pos = open[current_pos_index];
+
+        // This is the last node, return its cumulative risk
+        if current_pos.x == width - 1 && current_pos.y == height - 1 {
+            return current_pos.cumulative_score;
+        }
+
+        // Swap current pos from open to closed set
+        open.swap_remove(current_pos_index);
+        closed[(current_pos.x, current_pos.y)] = true;
+
+        // For each neighbor of lowest risk position:
+        //   Ignore if in closed set
+        //   Add to open list if not already
+        //   Otherwise compare costs and take lower for open list
+        for (x, y) in risk_map.neighbors4(current_pos.x, current_pos.y) {
+            if closed[(x, y)] {
+                continue;
+            }
+
+            let neighbor = Pos {
+                x,
+                y,
+                cumulative_score: current_pos.cumulative_score + risk_map[(x, y)],
+                heuristic_distance: get_heuristic_distance(x, y, risk_map),
+            };
+
+            let mut is_on_open_list = false;
+
+            for i in 0..open.len() {
+                if neighbor == open[i] {
+                    is_on_open_list = true;
+
+                    // Compare costs
+                    if neighbor.cumulative_score < open[i].cumulative_score {
+                        open[i].cumulative_score = neighbor.cumulative_score;
+                    }
+
+                    break;
+                }
+            }
+
+            if !is_on_open_list {
+                open.push(neighbor);
+            }
+        }
+    }
+
+    return u32::MAX;
+}
+
+// Risk levels are 1 to 9, so a 0 is rejected like any other bad digit
+pub fn parse_risk_map(input: &str) -> AocResult<Grid<u32>> {
+    const RADIX: u32 = 10;
+    return Grid::parse(input, "risk level", |val| val.to_digit(RADIX).filter(|risk| *risk != 0));
+}
+
+// Increase map size to 5x with +1 risk values going down and right, wrapping from 9 back to 1
+pub fn expand_risk_map(risk_map: &Grid<u32>) -> Grid<u32> {
+    return risk_map.tile(5, 5, |risk, tile_x, tile_y| (risk - 1 + tile_x as u32 + tile_y as u32) % 9 + 1);
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    return Ok(get_lowest_risk(&parse_risk_map(input)?));
+}
+
+pub fn part2(input: &str) -> AocResult<u32> {
+    return Ok(get_lowest_risk(&expand_risk_map(&parse_risk_map(input)?)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AocError;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day15.txt");
+
+    #[test]
+    fn gets_lowest_risk() {
+        assert_eq!(40, get_lowest_risk(&parse_risk_map(EXAMPLE).unwrap()));
+        assert_eq!(4, get_lowest_risk(&parse_risk_map("12\n92\n").unwrap()));
+    }
+
+    #[test]
+    fn expands_risk_map() {
+        let risk_map = expand_risk_map(&parse_risk_map("8\n").unwrap());
+
+        assert_eq!(&[8, 9, 1, 2, 3], risk_map.row(0));
+        assert_eq!(&[3, 4, 5, 6, 7], risk_map.row(4));
+
+        let risk_map = expand_risk_map(&parse_risk_map(EXAMPLE).unwrap());
+        assert_eq!(315, get_lowest_risk(&risk_map));
+    }
+
+    #[test]
+    fn rejects_zero_risk() {
+        assert_eq!(AocError::parse(2, 2, "bad risk level '0'"), parse_risk_map("12\n90\n").err().unwrap());
+    }
+}
@@ -0,0 +1,1544 @@
+// Day 16: Packet Decoder
+
+use std::io::{self, BufRead, Read};
+use std::marker::PhantomData;
+use std::mem;
+
+use crate::bigint::BigUint;
+use crate::error::{self, AocError, AocResult};
+
+// How deeply packets may be nested unless a limit is given, far more than any real transmission needs
+pub const DEFAULT_MAX_DEPTH: usize = 1000;
+
+#[derive(Clone, Copy)]
+pub struct PacketHeader {
+    pub version: u8,
+    pub type_id: u8,
+}
+
+// How an operator packet gives the size of its sub-packets
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LengthType {
+    // Length type ID 0, a 15 bit count of the bits in all sub-packets
+    TotalBits,
+
+    // Length type ID 1, an 11 bit count of the sub-packets
+    PacketCount,
+}
+
+#[derive(Clone)]
+pub struct Packet {
+    pub header: PacketHeader,
+    pub is_operator: bool,
+
+    // Literal values can be any size, only evaluating them needs them to fit in a smaller type
+    pub value: BigUint,
+    pub length_type: LengthType,
+    pub sub_packets: Vec<Packet>,
+
+    // Bit the packet starts at in its transmission, 0 for packets built rather than decoded
+    pub position: usize,
+}
+
+impl Packet {
+    pub fn literal(version: u8, value: u64) -> Packet {
+        return Packet::big_literal(version, BigUint::from(value));
+    }
+
+    pub fn big_literal(version: u8, value: BigUint) -> Packet {
+        return Packet {
+            header: PacketHeader {
+                version,
+                type_id: 4,
+            },
+            is_operator: false,
+            value,
+            length_type: LengthType::TotalBits,
+            sub_packets: Vec::<Packet>::new(),
+            position: 0,
+        };
+    }
+
+    pub fn operator(version: u8, type_id: u8, length_type: LengthType, sub_packets: Vec<Packet>) -> Packet {
+        return Packet {
+            header: PacketHeader {
+                version,
+                type_id,
+            },
+            is_operator: true,
+            value: BigUint::zero(),
+            length_type,
+            sub_packets,
+            position: 0,
+        };
+    }
+}
+
+// Dropping the sub-packets the usual way would recurse once per level of nesting, so flatten them first
+impl Drop for Packet {
+    fn drop(&mut self) {
+        let mut sub_packets = mem::take(&mut self.sub_packets);
+
+        while let Some(mut sub_packet) = sub_packets.pop() {
+            sub_packets.append(&mut sub_packet.sub_packets);
+        }
+    }
+}
+
+// Convert hex to byte with value represented in the 4 lsbs
+pub fn hex_to_nibble(hex_char: char) -> AocResult<u8> {
+    const RADIX: u32 = 16;
+
+    match hex_char.to_digit(RADIX) {
+        Some(nibble) => return Ok(nibble as u8),
+        None => return Err(AocError::Input(format!("bad hex character '{}'", hex_char))),
+    }
+}
+
+// Pack hex input into bytes, two hex characters per byte with a trailing odd character padded with zeros
+pub fn hex_to_bytes(hex_input: &str) -> AocResult<Vec<u8>> {
+    return hex_line_to_bytes(hex_input, 1, 1);
+}
+
+// Pack a line of hex into bytes, giving any bad character's position counting from line_num and first_column
+fn hex_line_to_bytes(hex_input: &str, line_num: usize, first_column: usize) -> AocResult<Vec<u8>> {
+    let mut bytes = Vec::<u8>::with_capacity(hex_input.len().div_ceil(2));
+
+    for (char_index, hex_char) in hex_input.chars().enumerate() {
+        let nibble = match hex_to_nibble(hex_char) {
+            Ok(nibble) => nibble,
+            Err(AocError::Input(message)) => return Err(AocError::parse(line_num, first_column + char_index, message)),
+            Err(err) => return Err(err),
+        };
+
+        if char_index % 2 == 0 {
+            bytes.push(nibble << 4);
+        }
+        else {
+            let last_index = bytes.len() - 1;
+            bytes[last_index] |= nibble;
+        }
+    }
+
+    return Ok(bytes);
+}
+
+// Turns a stream of hex characters into the bytes they stand for as they are read
+// The transmission ends at the end of the stream or the end of the first line
+pub struct HexDecoder<R: BufRead> {
+    source: io::Bytes<R>,
+    column: usize,
+    finished: bool,
+}
+
+impl<R: BufRead> HexDecoder<R> {
+    pub fn new(source: R) -> HexDecoder<R> {
+        return HexDecoder {
+            source: source.bytes(),
+            column: 0,
+            finished: false,
+        };
+    }
+
+    fn next_nibble(&mut self) -> io::Result<Option<u8>> {
+        if self.finished {
+            return Ok(None);
+        }
+
+        let hex_byte = match self.source.next() {
+            Some(hex_byte) => hex_byte?,
+            None => {
+                self.finished = true;
+                return Ok(None);
+            },
+        };
+
+        self.column += 1;
+
+        if hex_byte == b'\n' || hex_byte == b'\r' {
+            self.finished = true;
+            return Ok(None);
+        }
+
+        match hex_to_nibble(hex_byte as char) {
+            Ok(nibble) => return Ok(Some(nibble)),
+            Err(_) => return Err(io::Error::new(io::ErrorKind::InvalidData, format!("bad hex character '{}' at column {}", hex_byte as char, self.column))),
+        }
+    }
+}
+
+impl<R: BufRead> Read for HexDecoder<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let mut num_read = 0;
+
+        while num_read < buf.len() {
+            let high_nibble = match self.next_nibble()? {
+                Some(nibble) => nibble,
+                None => break,
+            };
+
+            let low_nibble = self.next_nibble()?.unwrap_or(0);
+            buf[num_read] = high_nibble << 4 | low_nibble;
+            num_read += 1;
+        }
+
+        return Ok(num_read);
+    }
+}
+
+// Reads bits most significant first from a source of bytes, only reading a byte once its first bit is needed
+pub struct BitReader<R: Read> {
+    source: R,
+    current_byte: u8,
+    bits_left_in_byte: u32,
+    position: usize,
+    ended: bool,
+}
+
+impl<R: Read> BitReader<R> {
+    pub fn new(source: R) -> BitReader<R> {
+        return BitReader {
+            source,
+            current_byte: 0,
+            bits_left_in_byte: 0,
+            position: 0,
+            ended: false,
+        };
+    }
+
+    // Number of bits read so far
+    pub fn position(&self) -> usize {
+        return self.position;
+    }
+
+    // Whether a read has run past the last bit
+    pub fn ended(&self) -> bool {
+        return self.ended;
+    }
+
+    pub fn read_bit(&mut self) -> AocResult<u8> {
+        if self.bits_left_in_byte == 0 {
+            let mut next_byte = [0; 1];
+
+            match self.source.read_exact(&mut next_byte) {
+                Ok(()) => {},
+                Err(err) if err.kind() == io::ErrorKind::UnexpectedEof => {
+                    self.ended = true;
+                    return Err(AocError::Input(format!("transmission ends at bit {}", self.position)));
+                },
+                Err(err) => return Err(AocError::Input(err.to_string())),
+            }
+
+            self.current_byte = next_byte[0];
+            self.bits_left_in_byte = 8;
+        }
+
+        self.bits_left_in_byte -= 1;
+        self.position += 1;
+
+        return Ok((self.current_byte >> self.bits_left_in_byte) & 1);
+    }
+
+    // Read a number made of the next num_bits bits, which must be at most 64
+    pub fn read_bits(&mut self, num_bits: u32) -> AocResult<u64> {
+        let mut val: u64 = 0;
+
+        for _ in 0..num_bits {
+            val <<= 1;
+            val |= self.read_bit()? as u64;
+        }
+
+        return Ok(val);
+    }
+}
+
+pub fn extract_header<R: Read>(reader: &mut BitReader<R>) -> AocResult<PacketHeader> {
+    let packet_version = reader.read_bits(3)? as u8;
+    let packet_type = reader.read_bits(3)? as u8;
+
+    return Ok(PacketHeader {
+        version: packet_version,
+        type_id: packet_type,
+    });
+}
+
+// Extract value from a literal packet, leaving the reader at the end of the packet
+// The value can have any number of groups so it is kept as a big integer
+pub fn extract_literal_value<R: Read>(reader: &mut BitReader<R>) -> AocResult<BigUint> {
+    let mut val = BigUint::zero();
+
+    while reader.read_bit()? == 1 {
+        val.push_nibble(reader.read_bits(4)? as u8);
+    }
+
+    val.push_nibble(reader.read_bits(4)? as u8);
+
+    return Ok(val);
+}
+
+// Read part of the packet starting at start_position, saying which packet was cut off if the transmission ends
+fn read_in_packet<R: Read, T>(reader: &mut BitReader<R>, start_position: usize, read: impl FnOnce(&mut BitReader<R>) -> AocResult<T>) -> AocResult<T> {
+    match read(reader) {
+        Err(AocError::Input(message)) if reader.ended() => return Err(AocError::Input(format!("packet at bit {} is cut off, {}", start_position, message))),
+        result => return result,
+    }
+}
+
+// Where an operator's sub-packets stop
+enum SubPacketsEnd {
+    AtBit(usize),
+    AfterCount(usize),
+}
+
+// Read a packet up to its sub-packets, giving where they stop if it is an operator
+fn extract_packet_start<R: Read>(reader: &mut BitReader<R>) -> AocResult<(Packet, Option<SubPacketsEnd>)> {
+    let start_position = reader.position();
+    let header = read_in_packet(reader, start_position, extract_header)?;
+
+    let mut packet = Packet {
+        header,
+        is_operator: true,
+        value: BigUint::zero(),
+        length_type: LengthType::TotalBits,
+        sub_packets: Vec::<Packet>::new(),
+        position: start_position,
+    };
+
+    // This is a literal value packet
+    if header.type_id == 4 {
+        packet.is_operator = false;
+        packet.value = read_in_packet(reader, start_position, extract_literal_value)?;
+
+        return Ok((packet, None));
+    }
+    // This is an operator packet
+    else {
+        let length_type_id = read_in_packet(reader, start_position, |reader| reader.read_bit())?;
+
+        // Next 15 bits determine length
+        if length_type_id == 0 {
+            let num_bits_in_packet = read_in_packet(reader, start_position, |reader| reader.read_bits(15))? as usize;
+            return Ok((packet, Some(SubPacketsEnd::AtBit(reader.position() + num_bits_in_packet))));
+        }
+        // Next 11 bits determine number of sub-packets
+        else {
+            packet.length_type = LengthType::PacketCount;
+            let num_packets_in_packet = read_in_packet(reader, start_position, |reader| reader.read_bits(11))? as usize;
+            return Ok((packet, Some(SubPacketsEnd::AfterCount(num_packets_in_packet))));
+        }
+    }
+}
+
+// Decode a packet and everything in it, refusing packets nested more than max_depth deep
+// Operators being filled in are kept on a stack of their own rather than the call stack, so any depth is safe to try
+pub fn extract_packet_with_max_depth<R: Read>(reader: &mut BitReader<R>, max_depth: usize) -> AocResult<Packet> {
+    let mut open_operators = Vec::<(Packet, SubPacketsEnd)>::new();
+
+    loop {
+        let (packet, sub_packets_end) = extract_packet_start(reader)?;
+
+        if open_operators.len() >= max_depth {
+            return Err(AocError::Input(format!("packet at bit {} is nested more than {} packets deep", packet.position, max_depth)));
+        }
+
+        let mut finished_packet = match sub_packets_end {
+            Some(sub_packets_end) => {
+                open_operators.push((packet, sub_packets_end));
+                None
+            },
+            None => Some(packet),
+        };
+
+        // Hand the finished packet to the operator holding it, closing every operator that is now complete
+        loop {
+            if let Some(packet) = finished_packet.take() {
+                match open_operators.last_mut() {
+                    Some((operator, _)) => operator.sub_packets.push(packet),
+                    None => return Ok(packet),
+                }
+            }
+
+            let is_complete = match open_operators.last() {
+                Some((operator, SubPacketsEnd::AtBit(stop_position))) => {
+                    // Sub-packets must exactly fill the given length
+                    if reader.position() > *stop_position {
+                        return Err(AocError::Input(format!("operator packet at bit {} has sub-packets that run past its end at bit {}", operator.position, stop_position)));
+                    }
+
+                    reader.position() == *stop_position
+                },
+                Some((operator, SubPacketsEnd::AfterCount(num_packets))) => operator.sub_packets.len() == *num_packets,
+                None => false,
+            };
+
+            if !is_complete {
+                break;
+            }
+
+            finished_packet = open_operators.pop().map(|(operator, _)| operator);
+        }
+    }
+}
+
+pub fn extract_packet<R: Read>(reader: &mut BitReader<R>) -> AocResult<Packet> {
+    return extract_packet_with_max_depth(reader, DEFAULT_MAX_DEPTH);
+}
+
+pub fn extract_all_packets(transmission: &[u8]) -> AocResult<Packet> {
+    return extract_packet(&mut BitReader::new(transmission));
+}
+
+// Add a description of every problem evaluating the packet would run into to problems, each with the bit it is at
+pub fn check_packet(packet: &Packet, problems: &mut Vec<String>) {
+    let mut packets_left = vec![packet];
+
+    while let Some(packet) = packets_left.pop() {
+        let type_id = packet.header.type_id;
+        let name = operator_name(type_id).unwrap_or("operator");
+
+        // Decoded type IDs always fit in 3 bits, built packets can have anything
+        if type_id > 7 || packet.is_operator == (type_id == 4) {
+            problems.push(format!("{} packet at bit {} has unknown type ID {}", if packet.is_operator {"operator"} else {"literal"}, packet.position, type_id));
+        }
+        else if packet.is_operator {
+            match type_id {
+                2 | 3 if packet.sub_packets.is_empty() => problems.push(format!("{} packet at bit {} has no sub-packets", name, packet.position)),
+                5..=7 if packet.sub_packets.len() != 2 => {
+                    problems.push(format!("{} packet at bit {} has {} sub-packets instead of 2", name, packet.position, packet.sub_packets.len()));
+                },
+                _ => {},
+            }
+        }
+
+        // Reversed so the first sub-packet is checked next
+        packets_left.extend(packet.sub_packets.iter().rev());
+    }
+}
+
+// Decode the outermost packet and make sure everything about the transmission is right,
+// including that the padding after the packet is all zeros, reporting every problem found
+pub fn validate_transmission(transmission: &[u8], max_depth: usize) -> AocResult<Packet> {
+    let mut reader = BitReader::new(transmission);
+    let packet = extract_packet_with_max_depth(&mut reader, max_depth)?;
+    let end_position = reader.position();
+    let mut problems = Vec::<String>::new();
+
+    check_packet(&packet, &mut problems);
+
+    while let Ok(padding_bit) = reader.read_bit() {
+        if padding_bit != 0 {
+            problems.push(format!("non-zero padding at bit {} after the packet ending at bit {}", reader.position() - 1, end_position));
+            break;
+        }
+    }
+
+    if !problems.is_empty() {
+        return Err(AocError::Input(problems.join("; ")));
+    }
+
+    return Ok(packet);
+}
+
+// Decode the outermost packet straight from a stream of hex characters, without reading the whole stream first
+pub fn read_transmission<R: BufRead>(hex_source: R) -> AocResult<Packet> {
+    return extract_packet(&mut BitReader::new(HexDecoder::new(hex_source)));
+}
+
+// Add the lowest num_bits bits of a value to the end of the bits, most significant first
+fn push_bits(value: u64, num_bits: usize, bits: &mut Vec<u8>) {
+    for bit_index in (0..num_bits).rev() {
+        bits.push(((value >> bit_index) & 1) as u8);
+    }
+}
+
+// Add a literal value as 4 bit groups, each led by a 1 except the last which is led by a 0
+pub fn encode_literal_value(value: &BigUint, bits: &mut Vec<u8>) {
+    let num_groups = value.bit_len().div_ceil(4).max(1);
+
+    for group_index in (0..num_groups).rev() {
+        bits.push(if group_index > 0 {1} else {0});
+        push_bits(value.nibble(group_index) as u64, 4, bits);
+    }
+}
+
+pub fn encode_packet(packet: &Packet, bits: &mut Vec<u8>) -> AocResult<()> {
+    if packet.header.version > 7 || packet.header.type_id > 7 {
+        return Err(AocError::Input(format!("version {} and type ID {} must both fit in 3 bits", packet.header.version, packet.header.type_id)));
+    }
+
+    if packet.is_operator == (packet.header.type_id == 4) {
+        return Err(AocError::Input(format!("type ID {} does not match a {} packet", packet.header.type_id, if packet.is_operator {"operator"} else {"literal"})));
+    }
+
+    push_bits(packet.header.version as u64, 3, bits);
+    push_bits(packet.header.type_id as u64, 3, bits);
+
+    if !packet.is_operator {
+        encode_literal_value(&packet.value, bits);
+        return Ok(());
+    }
+
+    // Encode the sub-packets first to find their length
+    let mut sub_packet_bits = Vec::<u8>::new();
+
+    for sub_packet in &packet.sub_packets {
+        encode_packet(sub_packet, &mut sub_packet_bits)?;
+    }
+
+    // Use the packet's own length type where it fits and the other one where it does not, which the format has for
+    // sub-packets too long for a 15 bit length or too many for an 11 bit count
+    let fits_total_bits = sub_packet_bits.len() < 1 << 15;
+    let fits_packet_count = packet.sub_packets.len() < 1 << 11;
+
+    if !fits_total_bits && !fits_packet_count {
+        return Err(AocError::Input(format!("{} sub-packets in {} bits fit neither an 11 bit count nor a 15 bit length",
+                                           packet.sub_packets.len(), sub_packet_bits.len())));
+    }
+
+    let length_type = match packet.length_type {
+        LengthType::TotalBits if !fits_total_bits => LengthType::PacketCount,
+        LengthType::PacketCount if !fits_packet_count => LengthType::TotalBits,
+        length_type => length_type,
+    };
+
+    match length_type {
+        LengthType::TotalBits => {
+            bits.push(0);
+            push_bits(sub_packet_bits.len() as u64, 15, bits);
+        },
+        LengthType::PacketCount => {
+            bits.push(1);
+            push_bits(packet.sub_packets.len() as u64, 11, bits);
+        },
+    }
+
+    bits.extend_from_slice(&sub_packet_bits);
+    return Ok(());
+}
+
+// Convert bits to hex, padding the end with zeros to a whole number of bytes
+pub fn bits_to_hex(bits: &[u8]) -> String {
+    let mut hex = String::new();
+    let mut padded_bits = bits.to_vec();
+
+    while !padded_bits.len().is_multiple_of(8) {
+        padded_bits.push(0);
+    }
+
+    for nibble_bits in padded_bits.chunks(4) {
+        let nibble = nibble_bits[0] << 3 | nibble_bits[1] << 2 | nibble_bits[2] << 1 | nibble_bits[3];
+        hex.push_str(&format!("{:X}", nibble));
+    }
+
+    return hex;
+}
+
+// Encode a packet and everything in it as a hex transmission
+pub fn encode_transmission(packet: &Packet) -> AocResult<String> {
+    let mut bits = Vec::<u8>::new();
+    encode_packet(packet, &mut bits)?;
+    return Ok(bits_to_hex(&bits));
+}
+
+// Works out a result for each packet from the results of its sub-packets, so every pass over the packets shares one walk
+pub trait PacketVisitor {
+    type Output;
+
+    fn visit_literal(&mut self, packet: &Packet) -> AocResult<Self::Output>;
+
+    // Called once all of the operator's sub-packets have been visited, with their results in order
+    fn visit_operator(&mut self, packet: &Packet, sub_results: Vec<Self::Output>) -> AocResult<Self::Output>;
+}
+
+// Visit every packet, sub-packets before the packets holding them
+// Operators being visited are kept on a stack of their own rather than the call stack, so any depth is safe
+pub fn walk_packet<V: PacketVisitor>(packet: &Packet, visitor: &mut V) -> AocResult<V::Output> {
+    let mut open_operators = Vec::<(&Packet, Vec<V::Output>)>::new();
+    let mut next_packet = packet;
+
+    loop {
+        // Go down to the first packet with nothing left inside it to visit
+        while next_packet.is_operator && !next_packet.sub_packets.is_empty() {
+            open_operators.push((next_packet, Vec::<V::Output>::with_capacity(next_packet.sub_packets.len())));
+            next_packet = &next_packet.sub_packets[0];
+        }
+
+        let mut result = if next_packet.is_operator {
+            visitor.visit_operator(next_packet, Vec::<V::Output>::new())?
+        }
+        else {
+            visitor.visit_literal(next_packet)?
+        };
+
+        // Hand the result up, visiting every operator that now has all of its sub-packets' results
+        loop {
+            let (operator, mut sub_results) = match open_operators.pop() {
+                Some(open_operator) => open_operator,
+                None => return Ok(result),
+            };
+
+            sub_results.push(result);
+
+            if sub_results.len() < operator.sub_packets.len() {
+                next_packet = &operator.sub_packets[sub_results.len()];
+                open_operators.push((operator, sub_results));
+                break;
+            }
+
+            result = visitor.visit_operator(operator, sub_results)?;
+        }
+    }
+}
+
+// Adds up the version numbers of a packet and everything in it
+pub struct VersionSum;
+
+impl PacketVisitor for VersionSum {
+    type Output = u32;
+
+    fn visit_literal(&mut self, packet: &Packet) -> AocResult<u32> {
+        return Ok(packet.header.version as u32);
+    }
+
+    fn visit_operator(&mut self, packet: &Packet, sub_results: Vec<u32>) -> AocResult<u32> {
+        return Ok(packet.header.version as u32 + sub_results.iter().sum::<u32>());
+    }
+}
+
+pub fn count_version_numbers(packet: &Packet) -> u32 {
+    match walk_packet(packet, &mut VersionSum) {
+        Ok(version_num_sum) => return version_num_sum,
+        Err(_) => unreachable!("summing versions cannot fail"),
+    }
+}
+
+// A kind of number packets can be evaluated as
+pub trait PacketValue: Clone + Ord + Sized {
+    fn from_u64(value: u64) -> Self;
+
+    // These give None when the result does not fit
+    fn from_literal(literal: &BigUint) -> Option<Self>;
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+
+impl PacketValue for u64 {
+    fn from_u64(value: u64) -> u64 {
+        return value;
+    }
+
+    fn from_literal(literal: &BigUint) -> Option<u64> {
+        return literal.to_u64();
+    }
+
+    fn checked_add(&self, other: &u64) -> Option<u64> {
+        return u64::checked_add(*self, *other);
+    }
+
+    fn checked_mul(&self, other: &u64) -> Option<u64> {
+        return u64::checked_mul(*self, *other);
+    }
+}
+
+impl PacketValue for BigUint {
+    fn from_u64(value: u64) -> BigUint {
+        return BigUint::from(value);
+    }
+
+    fn from_literal(literal: &BigUint) -> Option<BigUint> {
+        return Some(literal.clone());
+    }
+
+    fn checked_add(&self, other: &BigUint) -> Option<BigUint> {
+        return Some(self.add(other));
+    }
+
+    fn checked_mul(&self, other: &BigUint) -> Option<BigUint> {
+        return Some(self.mul(other));
+    }
+}
+
+fn too_big_error(what: &str) -> AocError {
+    return AocError::NoSolution(format!("{} is too big for 64 bits, evaluate with big integers instead", what));
+}
+
+pub fn get_packet_sum<V: PacketValue>(values: Vec<V>) -> AocResult<V> {
+    let mut sum = V::from_u64(0);
+
+    for value in &values {
+        sum = sum.checked_add(value).ok_or_else(|| too_big_error("sum"))?;
+    }
+
+    return Ok(sum);
+}
+
+pub fn get_packet_product<V: PacketValue>(values: Vec<V>) -> AocResult<V> {
+    let mut product = V::from_u64(1);
+
+    for value in &values {
+        product = product.checked_mul(value).ok_or_else(|| too_big_error("product"))?;
+    }
+
+    return Ok(product);
+}
+
+pub fn get_packet_min<V: PacketValue>(position: usize, values: Vec<V>) -> AocResult<V> {
+    return values.into_iter().min().ok_or_else(|| AocError::Input(format!("min packet at bit {} has no sub-packets", position)));
+}
+
+pub fn get_packet_max<V: PacketValue>(position: usize, values: Vec<V>) -> AocResult<V> {
+    return values.into_iter().max().ok_or_else(|| AocError::Input(format!("max packet at bit {} has no sub-packets", position)));
+}
+
+// Take the values of both sub-packets of a comparison packet
+fn get_packet_pair<V: PacketValue>(position: usize, values: Vec<V>) -> AocResult<(V, V)> {
+    if values.len() != 2 {
+        return Err(AocError::Input(format!("comparison packet at bit {} has {} sub-packets instead of 2", position, values.len())));
+    }
+
+    let mut values = values.into_iter();
+    return Ok((values.next().unwrap(), values.next().unwrap()));
+}
+
+pub fn get_packet_gt<V: PacketValue>(position: usize, values: Vec<V>) -> AocResult<V> {
+    let (first, second) = get_packet_pair(position, values)?;
+    return Ok(V::from_u64(if first > second {1} else {0}));
+}
+
+pub fn get_packet_lt<V: PacketValue>(position: usize, values: Vec<V>) -> AocResult<V> {
+    let (first, second) = get_packet_pair(position, values)?;
+    return Ok(V::from_u64(if first < second {1} else {0}));
+}
+
+pub fn get_packet_et<V: PacketValue>(position: usize, values: Vec<V>) -> AocResult<V> {
+    let (first, second) = get_packet_pair(position, values)?;
+    return Ok(V::from_u64(if first == second {1} else {0}));
+}
+
+// Work out an operator's value from the values of its sub-packets, with position being the bit the operator starts at
+pub fn apply_operator<V: PacketValue>(type_id: u8, position: usize, values: Vec<V>) -> AocResult<V> {
+    match type_id {
+        0 => return get_packet_sum(values),
+        1 => return get_packet_product(values),
+        2 => return get_packet_min(position, values),
+        3 => return get_packet_max(position, values),
+        5 => return get_packet_gt(position, values),
+        6 => return get_packet_lt(position, values),
+        7 => return get_packet_et(position, values),
+        _ => return Err(AocError::Input(format!("bad packet type ID {}", type_id))),
+    }
+}
+
+// Evaluates packets as any kind of number, failing if a value does not fit in it
+pub struct Evaluator<V> {
+    value_type: PhantomData<V>,
+}
+
+impl<V: PacketValue> Evaluator<V> {
+    pub fn new() -> Evaluator<V> {
+        return Evaluator {
+            value_type: PhantomData,
+        };
+    }
+}
+
+impl<V: PacketValue> Default for Evaluator<V> {
+    fn default() -> Evaluator<V> {
+        return Evaluator::new();
+    }
+}
+
+impl<V: PacketValue> PacketVisitor for Evaluator<V> {
+    type Output = V;
+
+    fn visit_literal(&mut self, packet: &Packet) -> AocResult<V> {
+        return V::from_literal(&packet.value).ok_or_else(|| too_big_error(&format!("literal {}", packet.value)));
+    }
+
+    fn visit_operator(&mut self, packet: &Packet, sub_results: Vec<V>) -> AocResult<V> {
+        return apply_operator(packet.header.type_id, packet.position, sub_results);
+    }
+}
+
+pub fn evaluate_packet<V: PacketValue>(packet: &Packet) -> AocResult<V> {
+    return walk_packet(packet, &mut Evaluator::new());
+}
+
+pub fn solve_packet(packet: &Packet) -> AocResult<u64> {
+    return evaluate_packet(packet);
+}
+
+// Solve a packet with big integers so no value is ever too big
+pub fn solve_packet_big(packet: &Packet) -> AocResult<BigUint> {
+    return evaluate_packet(packet);
+}
+
+// One step of a compiled packet, run against a stack of values
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Instruction {
+    // Push the value in the given literal slot
+    Literal(usize),
+
+    // Pop the values of the operator's sub-packets and push the operator's value
+    Operator {
+        type_id: u8,
+        num_operands: usize,
+
+        // Bit the operator started at, for error messages
+        position: usize,
+    },
+}
+
+// A packet flattened into instructions in the order they run, sub-packets first,
+// so it can be evaluated again and again with different literal values swapped in
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CompiledPacket {
+    instructions: Vec<Instruction>,
+
+    // The packet's own literal values, one per slot in the order they appear in the transmission
+    literals: Vec<BigUint>,
+}
+
+impl PacketVisitor for CompiledPacket {
+    type Output = ();
+
+    fn visit_literal(&mut self, packet: &Packet) -> AocResult<()> {
+        self.instructions.push(Instruction::Literal(self.literals.len()));
+        self.literals.push(packet.value.clone());
+        return Ok(());
+    }
+
+    fn visit_operator(&mut self, packet: &Packet, sub_results: Vec<()>) -> AocResult<()> {
+        self.instructions.push(Instruction::Operator {
+            type_id: packet.header.type_id,
+            num_operands: sub_results.len(),
+            position: packet.position,
+        });
+
+        return Ok(());
+    }
+}
+
+impl CompiledPacket {
+    // Compile a packet that has been checked to have nothing wrong with it
+    pub fn compile(packet: &Packet) -> AocResult<CompiledPacket> {
+        let mut problems = Vec::<String>::new();
+        check_packet(packet, &mut problems);
+
+        if !problems.is_empty() {
+            return Err(AocError::Input(problems.join("; ")));
+        }
+
+        let mut compiled = CompiledPacket {
+            instructions: Vec::<Instruction>::new(),
+            literals: Vec::<BigUint>::new(),
+        };
+
+        walk_packet(packet, &mut compiled)?;
+        return Ok(compiled);
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        return &self.instructions;
+    }
+
+    pub fn literals(&self) -> &[BigUint] {
+        return &self.literals;
+    }
+
+    // Evaluate with the given values in place of the packet's literals, one per slot
+    pub fn evaluate<V: PacketValue>(&self, literals: &[V]) -> AocResult<V> {
+        if literals.len() != self.literals.len() {
+            return Err(AocError::Input(format!("expected {} literal values, given {}", self.literals.len(), literals.len())));
+        }
+
+        let mut stack = Vec::<V>::new();
+
+        for instruction in &self.instructions {
+            match *instruction {
+                Instruction::Literal(slot) => stack.push(literals[slot].clone()),
+                Instruction::Operator {type_id, num_operands, position} => {
+                    let operands = stack.split_off(stack.len() - num_operands);
+                    stack.push(apply_operator(type_id, position, operands)?);
+                },
+            }
+        }
+
+        // Compiling always leaves exactly the outermost packet's value
+        return Ok(stack.pop().unwrap());
+    }
+
+    // Evaluate with the packet's own literals, which gives the same as evaluate_packet
+    pub fn evaluate_original<V: PacketValue>(&self) -> AocResult<V> {
+        let mut literals = Vec::<V>::with_capacity(self.literals.len());
+
+        for literal in &self.literals {
+            literals.push(V::from_literal(literal).ok_or_else(|| too_big_error(&format!("literal {}", literal)))?);
+        }
+
+        return self.evaluate(&literals);
+    }
+}
+
+// Name an operator type ID the way it is written in expressions
+pub fn operator_name(type_id: u8) -> Option<&'static str> {
+    match type_id {
+        0 => return Some("sum"),
+        1 => return Some("product"),
+        2 => return Some("min"),
+        3 => return Some("max"),
+        5 => return Some("gt"),
+        6 => return Some("lt"),
+        7 => return Some("eq"),
+        _ => return None,
+    }
+}
+
+// Render a packet as an S-expression such as (sum 1 (product 2 3) (gt 4 5))
+pub fn packet_to_sexpr(packet: &Packet) -> String {
+    // Packets still to write, with None standing for the closing bracket of an operator
+    let mut parts_left = vec![Some(packet)];
+    let mut sexpr = String::new();
+
+    while let Some(part) = parts_left.pop() {
+        let packet = match part {
+            Some(packet) => packet,
+            None => {
+                sexpr.push(')');
+                continue;
+            },
+        };
+
+        if !sexpr.is_empty() && !sexpr.ends_with('(') {
+            sexpr.push(' ');
+        }
+
+        if !packet.is_operator {
+            sexpr.push_str(&packet.value.to_string());
+            continue;
+        }
+
+        match operator_name(packet.header.type_id) {
+            Some(name) => sexpr.push_str(&format!("({}", name)),
+            None => sexpr.push_str(&format!("(type{}", packet.header.type_id)),
+        }
+
+        parts_left.push(None);
+        parts_left.extend(packet.sub_packets.iter().rev().map(Some));
+    }
+
+    return sexpr;
+}
+
+// Render a packet as an indented tree, one line per packet with its version and type ID
+pub fn packet_to_tree(packet: &Packet) -> String {
+    let mut packets_left = vec![(packet, 0)];
+    let mut tree = String::new();
+
+    while let Some((packet, depth)) = packets_left.pop() {
+        let indent = "  ".repeat(depth);
+        let header = &packet.header;
+
+        if !packet.is_operator {
+            tree.push_str(&format!("{}literal {} (version {}, type {})\n", indent, packet.value, header.version, header.type_id));
+            continue;
+        }
+
+        let length_type_id = if packet.length_type == LengthType::TotalBits {0} else {1};
+        tree.push_str(&format!("{}{} (version {}, type {}, length type {}, {} sub-packets)\n", indent, operator_name(header.type_id).unwrap_or("unknown"),
+                               header.version, header.type_id, length_type_id, packet.sub_packets.len()));
+
+        packets_left.extend(packet.sub_packets.iter().rev().map(|sub_packet| (sub_packet, depth + 1)));
+    }
+
+    return tree;
+}
+
+// Get the type ID of an operator written the way operator_name gives it
+pub fn operator_type_id(name: &str) -> Option<u8> {
+    return (0..8).find(|type_id| operator_name(*type_id) == Some(name));
+}
+
+// A bracket or word of an assembler expression, with the line and column it starts at
+struct Token<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+// Split expressions into brackets and words, leaving out whitespace and comments starting with ;
+fn tokenize(text: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::<Token>::new();
+
+    for (line_num, line) in error::numbered_lines(text) {
+        let code = line.split(';').next().unwrap_or("");
+        let mut word_start: Option<usize> = None;
+
+        for (char_index, val) in code.char_indices() {
+            let ends_word = val.is_whitespace() || val == '(' || val == ')';
+
+            if ends_word {
+                if let Some(start) = word_start.take() {
+                    tokens.push(Token {text: &code[start..char_index], line: line_num, column: start + 1});
+                }
+
+                if !val.is_whitespace() {
+                    tokens.push(Token {text: &code[char_index..char_index + 1], line: line_num, column: char_index + 1});
+                }
+            }
+            else if word_start.is_none() {
+                word_start = Some(char_index);
+            }
+        }
+
+        if let Some(start) = word_start {
+            tokens.push(Token {text: &code[start..], line: line_num, column: start + 1});
+        }
+    }
+
+    return tokens;
+}
+
+// Split a word such as sum@3 into its name and version, which is 0 when not given
+fn parse_versioned<'a>(token: &Token<'a>) -> AocResult<(&'a str, u8)> {
+    let (name, version) = match token.text.split_once('@') {
+        Some((name, version)) => (name, version),
+        None => return Ok((token.text, 0)),
+    };
+
+    match version.parse::<u8>() {
+        Ok(version) if version <= 7 => return Ok((name, version)),
+        _ => return Err(AocError::parse(token.line, token.column + name.len() + 1, format!("version must be 0 to 7, found \"{}\"", version))),
+    }
+}
+
+// Split an operator name such as sum#1 into its name and length type ID, which is 0 for a length in bits when not given
+// Either way the encoder switches to the other length type if the sub-packets do not fit the one given
+fn parse_length_type<'a>(name: &'a str, token: &Token) -> AocResult<(&'a str, LengthType)> {
+    match name.split_once('#') {
+        None => return Ok((name, LengthType::TotalBits)),
+        Some((name, "0")) => return Ok((name, LengthType::TotalBits)),
+        Some((name, "1")) => return Ok((name, LengthType::PacketCount)),
+        Some((name, length_type_id)) => return Err(AocError::parse(token.line, token.column + name.len() + 1,
+                                                                   format!("length type must be 0 or 1, found \"{}\"", length_type_id))),
+    }
+}
+
+fn parse_decimal(digits: &str, token: &Token) -> AocResult<BigUint> {
+    const RADIX: u32 = 10;
+    let mut value = BigUint::zero();
+
+    if digits.is_empty() {
+        return Err(AocError::parse(token.line, token.column, format!("expected a literal value, found \"{}\"", token.text)));
+    }
+
+    for digit_char in digits.chars() {
+        match digit_char.to_digit(RADIX) {
+            Some(digit) => value = value.mul(&BigUint::from(RADIX as u64)).add(&BigUint::from(digit as u64)),
+            None => return Err(AocError::parse(token.line, token.column, format!("expected a literal value, found \"{}\"", token.text))),
+        }
+    }
+
+    return Ok(value);
+}
+
+// Build packets from expressions written the way packet_to_sexpr prints them, such as (sum 1 (product@3 2 3)),
+// where any operator or literal can be given a version with @ and anything after a ; on a line is a comment
+// Operators can also be given a length type ID with #, as in (sum#1@3 1 2), which is written before the version
+// Sub-packet counts are not checked, so transmissions with mistakes in them can be written on purpose
+pub fn assemble_packets(text: &str) -> AocResult<Vec<Packet>> {
+    let tokens = tokenize(text);
+    let mut packets = Vec::<Packet>::new();
+
+    // Operators whose closing bracket has not been reached yet, along with their opening bracket
+    let mut open_operators = Vec::<(Packet, &Token)>::new();
+    let mut token_index = 0;
+
+    while token_index < tokens.len() {
+        let token = &tokens[token_index];
+        token_index += 1;
+
+        let finished_packet = match token.text {
+            "(" => {
+                let name_token = match tokens.get(token_index) {
+                    Some(name_token) if name_token.text != "(" && name_token.text != ")" => name_token,
+                    _ => return Err(AocError::parse(token.line, token.column, "expected an operator name after (")),
+                };
+
+                token_index += 1;
+                let (name, version) = parse_versioned(name_token)?;
+                let (name, length_type) = parse_length_type(name, name_token)?;
+
+                let type_id = match operator_type_id(name) {
+                    Some(type_id) => type_id,
+                    None => return Err(AocError::parse(name_token.line, name_token.column, format!("unknown operator \"{}\"", name))),
+                };
+
+                open_operators.push((Packet::operator(version, type_id, length_type, Vec::<Packet>::new()), token));
+                None
+            },
+            ")" => match open_operators.pop() {
+                Some((operator, _)) => Some(operator),
+                None => return Err(AocError::parse(token.line, token.column, ") does not close anything")),
+            },
+            _ => {
+                let (digits, version) = parse_versioned(token)?;
+                Some(Packet::big_literal(version, parse_decimal(digits, token)?))
+            },
+        };
+
+        if let Some(packet) = finished_packet {
+            match open_operators.last_mut() {
+                Some((operator, _)) => operator.sub_packets.push(packet),
+                None => packets.push(packet),
+            }
+        }
+    }
+
+    if let Some((_, token)) = open_operators.last() {
+        return Err(AocError::parse(token.line, token.column, "( is never closed"));
+    }
+
+    if packets.is_empty() {
+        return Err(AocError::Input("no expressions found".to_string()));
+    }
+
+    return Ok(packets);
+}
+
+pub fn assemble_packet(text: &str) -> AocResult<Packet> {
+    let mut packets = assemble_packets(text)?;
+
+    if packets.len() != 1 {
+        return Err(AocError::Input(format!("expected one expression, found {}", packets.len())));
+    }
+
+    return Ok(packets.remove(0));
+}
+
+// Assemble expressions into hex transmissions, one for each expression
+pub fn assemble_transmissions(text: &str) -> AocResult<Vec<String>> {
+    let mut transmissions = Vec::<String>::new();
+
+    for packet in assemble_packets(text)? {
+        transmissions.push(encode_transmission(&packet)?);
+    }
+
+    return Ok(transmissions);
+}
+
+// Get the bytes of the transmission on the first line
+pub fn parse_transmission(input: &str) -> AocResult<Vec<u8>> {
+    match input.lines().next() {
+        Some(line) if !line.is_empty() => return hex_to_bytes(line),
+        _ => return Err(AocError::Input("no transmission".to_string())),
+    }
+}
+
+// Decode every non-empty line as a transmission of its own, giving each one's line number with its packet or what is wrong with it
+pub fn decode_transmissions(input: &str, max_depth: usize) -> Vec<(usize, AocResult<Packet>)> {
+    let mut transmissions = Vec::<(usize, AocResult<Packet>)>::new();
+
+    for (line_num, line) in error::numbered_lines(input) {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // Whitespace around the hex is skipped, while columns still count from the start of the line
+        let first_column = line.chars().take_while(|val| val.is_whitespace()).count() + 1;
+        let packet = hex_line_to_bytes(line.trim(), line_num, first_column).and_then(|transmission| validate_transmission(&transmission, max_depth));
+
+        transmissions.push((line_num, packet));
+    }
+
+    return transmissions;
+}
+
+// Describe a packet by its version sum and value, evaluating with big integers if asked to
+pub fn describe_packet(packet: &Packet, big: bool) -> AocResult<String> {
+    let value = if big {solve_packet_big(packet)?.to_string()} else {solve_packet(packet)?.to_string()};
+    return Ok(format!("version sum {}, value {}", count_version_numbers(packet), value));
+}
+
+// Describe a packet by its version sum alone, which needs no evaluating
+pub fn describe_version_sum(packet: &Packet) -> AocResult<String> {
+    return Ok(format!("version sum {}", count_version_numbers(packet)));
+}
+
+// Describe each decoded transmission on a line of its own with the describe function given, along with its
+// S-expression and tree if dump is set, giving the description and how many transmissions could not be decoded or described
+pub fn describe_transmissions(transmissions: &[(usize, AocResult<Packet>)], describe: impl Fn(&Packet) -> AocResult<String>, dump: bool) -> (String, usize) {
+    let mut description = String::new();
+    let mut num_failed = 0;
+
+    for (line_num, packet) in transmissions {
+        let packet_description = match packet {
+            Ok(packet) => describe(packet),
+            Err(err) => Err(err.clone()),
+        };
+
+        if packet_description.is_err() {
+            num_failed += 1;
+        }
+
+        match packet_description {
+            Ok(packet_description) => description.push_str(&format!("line {}: {}\n", line_num, packet_description)),
+
+            // The line is already given, so only the column is worth keeping
+            Err(AocError::Parse {column, message, ..}) => description.push_str(&format!("line {}: error at column {}: {}\n", line_num, column, message)),
+            Err(err) => description.push_str(&format!("line {}: error, {}\n", line_num, err)),
+        }
+
+        if let Ok(packet) = packet {
+            if dump {
+                description.push_str(&format!("{}\n{}", packet_to_sexpr(packet), packet_to_tree(packet)));
+            }
+        }
+    }
+
+    return (description, num_failed);
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    let all_packets = validate_transmission(&parse_transmission(input)?, DEFAULT_MAX_DEPTH)?;
+    return Ok(count_version_numbers(&all_packets));
+}
+
+pub fn part2(input: &str) -> AocResult<u64> {
+    let all_packets = validate_transmission(&parse_transmission(input)?, DEFAULT_MAX_DEPTH)?;
+    return solve_packet(&all_packets);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet_from_hex(hex: &str) -> Packet {
+        return extract_all_packets(&hex_to_bytes(hex).unwrap()).unwrap();
+    }
+
+    #[test]
+    fn extracts_literal_packet() {
+        let transmission = hex_to_bytes("D2FE28").unwrap();
+        let mut reader = BitReader::new(transmission.as_slice());
+        let packet = extract_packet(&mut reader).unwrap();
+
+        assert_eq!((6, 4), (packet.header.version, packet.header.type_id));
+        assert!(!packet.is_operator);
+        assert_eq!(BigUint::from(2021), packet.value);
+        assert_eq!(21, reader.position());
+    }
+
+    #[test]
+    fn extracts_operator_packets() {
+        let packet = packet_from_hex("38006F45291200");
+        assert_eq!(vec![10, 20], packet.sub_packets.iter().map(|p| p.value.to_u64().unwrap()).collect::<Vec<u64>>());
+
+        let packet = packet_from_hex("EE00D40C823060");
+        assert_eq!(vec![1, 2, 3], packet.sub_packets.iter().map(|p| p.value.to_u64().unwrap()).collect::<Vec<u64>>());
+    }
+
+    #[test]
+    fn counts_version_numbers() {
+        assert_eq!(16, count_version_numbers(&packet_from_hex("8A004A801A8002F478")));
+        assert_eq!(12, count_version_numbers(&packet_from_hex("620080001611562C8802118E34")));
+        assert_eq!(23, count_version_numbers(&packet_from_hex("C0015000016115A2E0802F182340")));
+        assert_eq!(31, count_version_numbers(&packet_from_hex("A0016C880162017C3686B18A3D4780")));
+    }
+
+    #[test]
+    fn walks_packets_with_visitors() {
+        // Counts literals, sub-packets before their operators
+        struct LiteralCount(Vec<u8>);
+
+        impl PacketVisitor for LiteralCount {
+            type Output = usize;
+
+            fn visit_literal(&mut self, packet: &Packet) -> AocResult<usize> {
+                self.0.push(packet.header.version);
+                return Ok(1);
+            }
+
+            fn visit_operator(&mut self, packet: &Packet, sub_results: Vec<usize>) -> AocResult<usize> {
+                self.0.push(packet.header.version);
+                return Ok(sub_results.iter().sum());
+            }
+        }
+
+        let packet = packet_from_hex("8A004A801A8002F478");
+        let mut visitor = LiteralCount(Vec::<u8>::new());
+
+        assert_eq!(1, walk_packet(&packet, &mut visitor).unwrap());
+        assert_eq!(vec![6, 5, 1, 4], visitor.0);
+        assert_eq!(16, walk_packet(&packet, &mut VersionSum).unwrap());
+        assert_eq!(15, walk_packet(&packet, &mut Evaluator::<u64>::new()).unwrap());
+    }
+
+    #[test]
+    fn solves_packets() {
+        let examples = [
+            ("C200B40A82", 3), ("04005AC33890", 54), ("880086C3E88112", 7), ("CE00C43D881120", 9),
+            ("D8005AC2A8F0", 1), ("F600BC2D8F", 0), ("9C005AC2F8F0", 0), ("9C0141080250320F1802104A08", 1),
+        ];
+
+        for (hex, value) in examples {
+            assert_eq!(value, solve_packet(&packet_from_hex(hex)).unwrap(), "{}", hex);
+        }
+    }
+
+    #[test]
+    fn round_trips_examples() {
+        let examples = [
+            "D2FE28", "38006F45291200", "EE00D40C823060", "8A004A801A8002F478", "620080001611562C8802118E34",
+            "C0015000016115A2E0802F182340", "A0016C880162017C3686B18A3D4780", "9C0141080250320F1802104A08",
+        ];
+
+        for hex in examples {
+            assert_eq!(hex, encode_transmission(&packet_from_hex(hex)).unwrap());
+        }
+    }
+
+    #[test]
+    fn encodes_built_packets() {
+        let packet = Packet::operator(1, 0, LengthType::TotalBits, vec![
+            Packet::literal(2, 0),
+            Packet::operator(3, 1, LengthType::PacketCount, vec![Packet::literal(4, 6), Packet::literal(5, 1 << 40)]),
+        ]);
+
+        let decoded = packet_from_hex(&encode_transmission(&packet).unwrap());
+        assert_eq!(15, count_version_numbers(&decoded));
+        assert_eq!(LengthType::PacketCount, decoded.sub_packets[1].length_type);
+        assert_eq!(6 << 40, solve_packet(&decoded).unwrap());
+
+        assert!(encode_transmission(&Packet::literal(8, 1)).is_err());
+        assert!(encode_transmission(&Packet::operator(0, 4, LengthType::TotalBits, vec![])).is_err());
+    }
+
+    #[test]
+    fn switches_length_type_when_it_does_not_fit() {
+        // 500 literals of 71 bits each are too long for a 15 bit length, so they are counted instead
+        let literals = vec![Packet::literal(0, 1 << 50); 500];
+        let decoded = packet_from_hex(&encode_transmission(&Packet::operator(0, 0, LengthType::TotalBits, literals)).unwrap());
+
+        assert_eq!(LengthType::PacketCount, decoded.length_type);
+        assert_eq!(500, decoded.sub_packets.len());
+        assert_eq!(500 << 50, solve_packet(&decoded).unwrap());
+
+        // 2500 literals of 11 bits each are too many for an 11 bit count, but fit in a 15 bit length
+        let zeros = vec![Packet::literal(0, 0); 2500];
+        let decoded = packet_from_hex(&encode_transmission(&Packet::operator(0, 0, LengthType::PacketCount, zeros)).unwrap());
+
+        assert_eq!(LengthType::TotalBits, decoded.length_type);
+        assert_eq!(2500, decoded.sub_packets.len());
+
+        let zeros = vec![Packet::literal(0, 0); 3000];
+        assert!(encode_transmission(&Packet::operator(0, 0, LengthType::TotalBits, zeros)).is_err());
+    }
+
+    #[test]
+    fn prints_packets() {
+        let packet = Packet::operator(1, 0, LengthType::PacketCount, vec![
+            Packet::literal(2, 1),
+            Packet::operator(3, 1, LengthType::TotalBits, vec![Packet::literal(4, 2), Packet::literal(5, 3)]),
+            Packet::operator(6, 5, LengthType::PacketCount, vec![Packet::literal(7, 4), Packet::literal(0, 5)]),
+        ]);
+
+        assert_eq!("(sum 1 (product 2 3) (gt 4 5))", packet_to_sexpr(&packet));
+        assert_eq!("(eq (sum 1 3) (product 2 2))", packet_to_sexpr(&packet_from_hex("9C0141080250320F1802104A08")));
+
+        let tree = packet_to_tree(&packet);
+        let lines = tree.lines().collect::<Vec<&str>>();
+        assert_eq!(8, lines.len());
+        assert_eq!("sum (version 1, type 0, length type 1, 3 sub-packets)", lines[0]);
+        assert_eq!("  product (version 3, type 1, length type 0, 2 sub-packets)", lines[2]);
+        assert_eq!("    literal 2 (version 4, type 4)", lines[3]);
+    }
+
+    #[test]
+    fn compiles_packets() {
+        let compiled = CompiledPacket::compile(&packet_from_hex("9C0141080250320F1802104A08")).unwrap();
+
+        assert_eq!(7, compiled.instructions().len());
+        assert_eq!(Instruction::Operator {type_id: 7, num_operands: 2, position: 0}, compiled.instructions()[6]);
+        assert_eq!(vec![1, 3, 2, 2], compiled.literals().iter().map(|literal| literal.to_u64().unwrap()).collect::<Vec<u64>>());
+
+        // (eq (sum a b) (product c d))
+        assert_eq!(1, compiled.evaluate_original::<u64>().unwrap());
+        assert_eq!(0, compiled.evaluate::<u64>(&[1, 2, 3, 4]).unwrap());
+        assert_eq!(1, compiled.evaluate::<u64>(&[5, 7, 3, 4]).unwrap());
+        assert_eq!(AocError::Input("expected 4 literal values, given 2".to_string()), compiled.evaluate::<u64>(&[1, 2]).unwrap_err());
+
+        let examples = ["C200B40A82", "04005AC33890", "880086C3E88112", "CE00C43D881120", "D8005AC2A8F0", "F600BC2D8F", "9C005AC2F8F0"];
+
+        for hex in examples {
+            let packet = packet_from_hex(hex);
+            assert_eq!(solve_packet_big(&packet).unwrap(), CompiledPacket::compile(&packet).unwrap().evaluate_original::<BigUint>().unwrap(), "{}", hex);
+        }
+
+        assert!(CompiledPacket::compile(&Packet::operator(0, 5, LengthType::PacketCount, vec![Packet::literal(0, 1)])).is_err());
+    }
+
+    #[test]
+    fn assembles_expressions() {
+        let packet = assemble_packet("(eq (sum 1 3) (product 2 2))").unwrap();
+        assert_eq!("(eq (sum 1 3) (product 2 2))", packet_to_sexpr(&packet));
+        assert_eq!(1, solve_packet(&packet_from_hex(&encode_transmission(&packet).unwrap())).unwrap());
+
+        let packet = assemble_packet("; versions add up to 16\n(max@4\n  (min@1 (sum@5 15@6)))").unwrap();
+        assert_eq!(16, count_version_numbers(&packet));
+        assert_eq!(15, solve_packet(&packet).unwrap());
+
+        let transmissions = assemble_transmissions("(gt 1 2) (lt 1 2)\n36893488147419103232").unwrap();
+        assert_eq!(3, transmissions.len());
+        assert_eq!(1, solve_packet(&packet_from_hex(&transmissions[1])).unwrap());
+        assert_eq!("36893488147419103232", solve_packet_big(&packet_from_hex(&transmissions[2])).unwrap().to_string());
+
+        let packet = packet_from_hex(&assemble_transmissions("(sum#1@3 1 (product#0 2 3))").unwrap()[0]);
+        assert_eq!((3, LengthType::PacketCount), (packet.header.version, packet.length_type));
+        assert_eq!(LengthType::TotalBits, packet.sub_packets[1].length_type);
+
+        // More sub-packet bits than a 15 bit length holds are counted instead
+        let expression = format!("(sum {})", "1152921504606846976 ".repeat(500));
+        let packet = packet_from_hex(&assemble_transmissions(&expression).unwrap()[0]);
+        assert_eq!(LengthType::PacketCount, packet.length_type);
+        assert_eq!((500_u128 << 60).to_string(), solve_packet_big(&packet).unwrap().to_string());
+    }
+
+    #[test]
+    fn reports_bad_expressions() {
+        assert_eq!(AocError::parse(1, 2, "unknown operator \"add\""), assemble_packet("(add 1 2)").err().unwrap());
+        assert_eq!(AocError::parse(1, 10, "version must be 0 to 7, found \"8\""), assemble_packet("(sum 1 2@8)").err().unwrap());
+        assert_eq!(AocError::parse(1, 6, "length type must be 0 or 1, found \"2\""), assemble_packet("(sum#2@1 1 2)").err().unwrap());
+        assert_eq!(AocError::parse(2, 3, "expected a literal value, found \"x\""), assemble_packet("(sum 1\n  x)").err().unwrap());
+        assert_eq!(AocError::parse(1, 1, "( is never closed"), assemble_packet("(sum (sum 1 2)").err().unwrap());
+        assert_eq!(AocError::parse(1, 4, ") does not close anything"), assemble_packet("1 2)").err().unwrap());
+        assert_eq!(AocError::parse(1, 1, "expected an operator name after ("), assemble_packet("()").err().unwrap());
+        assert_eq!(AocError::Input("expected one expression, found 2".to_string()), assemble_packet("1 2").err().unwrap());
+    }
+
+    #[test]
+    fn decodes_transmission_per_line() {
+        let transmissions = decode_transmissions("8A004A801A8002F478\n\nC200B40A82\nD2G\n38006F4529\n0A000", DEFAULT_MAX_DEPTH);
+        assert_eq!(vec![1, 3, 4, 5, 6], transmissions.iter().map(|(line_num, _)| *line_num).collect::<Vec<usize>>());
+        assert_eq!(AocError::parse(4, 3, "bad hex character 'G'"), *transmissions[2].1.as_ref().err().unwrap());
+
+        let (description, num_failed) = describe_transmissions(&transmissions, |packet| describe_packet(packet, false), false);
+        assert_eq!(3, num_failed);
+        assert_eq!(vec![
+            "line 1: version sum 16, value 15",
+            "line 3: version sum 14, value 3",
+            "line 4: error at column 3: bad hex character 'G'",
+            "line 5: error, bad input: packet at bit 33 is cut off, transmission ends at bit 40",
+            "line 6: error, bad input: min packet at bit 0 has no sub-packets",
+        ], description.lines().collect::<Vec<&str>>());
+
+        let (description, _) = describe_transmissions(&transmissions[1..2], |packet| describe_packet(packet, true), true);
+        assert!(description.starts_with("line 3: version sum 14, value 3\n(sum 1 2)\nsum (version 6"));
+
+        // A value too big for 64 bits fails to evaluate, but its version sum can still be given
+        let overflowing = assemble_transmissions("(product 18446744073709551615 2)").unwrap().remove(0);
+        let transmissions = decode_transmissions(&format!("  C200B40A82 \n{}\n D2G", overflowing), DEFAULT_MAX_DEPTH);
+        assert_eq!(AocError::parse(3, 4, "bad hex character 'G'"), *transmissions[2].1.as_ref().err().unwrap());
+
+        let (description, num_failed) = describe_transmissions(&transmissions, |packet| describe_packet(packet, false), false);
+        assert_eq!(2, num_failed);
+        assert!(description.starts_with("line 1: version sum 14, value 3\nline 2: error, "));
+
+        let (description, num_failed) = describe_transmissions(&transmissions, describe_version_sum, false);
+        assert_eq!(1, num_failed);
+        assert!(description.starts_with("line 1: version sum 14\nline 2: version sum 0\n"));
+    }
+
+    #[test]
+    fn evaluates_big_values() {
+        // A literal of 20 nibbles only fits in a big integer
+        let mut big_value = BigUint::zero();
+        for _ in 0..20 {
+            big_value.push_nibble(0xF);
+        }
+
+        let packet = packet_from_hex(&encode_transmission(&Packet::big_literal(0, big_value.clone())).unwrap());
+        assert_eq!(big_value, solve_packet_big(&packet).unwrap());
+        assert!(matches!(solve_packet(&packet), Err(AocError::NoSolution(_))));
+
+        let packet = Packet::operator(0, 1, LengthType::PacketCount, vec![Packet::literal(0, u64::MAX), Packet::literal(0, 2)]);
+        assert_eq!(AocError::NoSolution("product is too big for 64 bits, evaluate with big integers instead".to_string()), solve_packet(&packet).unwrap_err());
+        assert_eq!("36893488147419103230", solve_packet_big(&packet).unwrap().to_string());
+
+        let packet = Packet::operator(0, 2, LengthType::PacketCount, vec![]);
+        assert!(solve_packet(&packet).is_err());
+    }
+
+    #[test]
+    fn reports_bad_hex() {
+        assert_eq!(AocError::parse(1, 3, "bad hex character 'G'"), hex_to_bytes("D2G").unwrap_err());
+        assert_eq!(AocError::Input("bad hex character 'G' at column 3".to_string()), read_transmission("D2G".as_bytes()).err().unwrap());
+    }
+
+    #[test]
+    fn reads_bits_across_bytes() {
+        assert_eq!(vec![0xD2, 0xFE, 0x20], hex_to_bytes("D2FE2").unwrap());
+
+        let mut reader = BitReader::new([0xD2, 0xFE].as_slice());
+        assert_eq!(0b110, reader.read_bits(3).unwrap());
+        assert_eq!(0b1001011111, reader.read_bits(10).unwrap());
+        assert_eq!(13, reader.position());
+        assert_eq!(0b110, reader.read_bits(3).unwrap());
+        assert_eq!(AocError::Input("transmission ends at bit 16".to_string()), reader.read_bit().unwrap_err());
+    }
+
+    #[test]
+    fn validates_transmissions() {
+        let validate_hex = |hex: &str| validate_transmission(&hex_to_bytes(hex).unwrap(), DEFAULT_MAX_DEPTH).err().map(|err| err.to_string());
+
+        assert_eq!(None, validate_hex("9C0141080250320F1802104A08"));
+        assert_eq!(Some("bad input: packet at bit 33 is cut off, transmission ends at bit 40".to_string()), validate_hex("38006F4529"));
+        assert_eq!(Some("bad input: non-zero padding at bit 23 after the packet ending at bit 21".to_string()), validate_hex("D2FE29"));
+
+        let comparison = Packet::operator(0, 5, LengthType::PacketCount, vec![
+            Packet::operator(0, 2, LengthType::PacketCount, vec![]), Packet::literal(0, 2), Packet::literal(0, 3),
+        ]);
+        assert_eq!(Some("bad input: gt packet at bit 0 has 3 sub-packets instead of 2; min packet at bit 18 has no sub-packets".to_string()),
+                   validate_hex(&encode_transmission(&comparison).unwrap()));
+
+        let mut unknown = Packet::literal(0, 1);
+        unknown.header.type_id = 9;
+        let mut problems = Vec::<String>::new();
+        check_packet(&unknown, &mut problems);
+        assert_eq!(vec!["literal packet at bit 0 has unknown type ID 9".to_string()], problems);
+    }
+
+    #[test]
+    fn handles_deep_nesting() {
+        // Sum packets each holding the next, down to a literal 7
+        const DEPTH: usize = 100_000;
+        let mut bits = Vec::<u8>::new();
+
+        for _ in 0..DEPTH {
+            push_bits(0, 6, &mut bits);
+            push_bits(1, 1, &mut bits);
+            push_bits(1, 11, &mut bits);
+        }
+
+        push_bits(4, 6, &mut bits);
+        push_bits(7, 5, &mut bits);
+        let transmission = hex_to_bytes(&bits_to_hex(&bits)).unwrap();
+
+        assert_eq!(AocError::Input("packet at bit 18000 is nested more than 1000 packets deep".to_string()),
+                   validate_transmission(&transmission, DEFAULT_MAX_DEPTH).err().unwrap());
+
+        let packet = validate_transmission(&transmission, usize::MAX).unwrap();
+        assert_eq!(7, solve_packet(&packet).unwrap());
+        assert_eq!(0, count_version_numbers(&packet));
+        assert_eq!(DEPTH * "(sum )".len() + 1, packet_to_sexpr(&packet).len());
+    }
+
+    #[test]
+    fn streams_hex_transmission() {
+        let packet = read_transmission("9C0141080250320F1802104A08\nnot read\n".as_bytes()).unwrap();
+        assert_eq!(1, solve_packet(&packet).unwrap());
+
+        // Truncated packets are an error rather than a panic
+        assert!(read_transmission("38006F4529".as_bytes()).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+// Day 17: Trick Shot
+
+use crate::error::{self, AocError, AocResult};
+
+pub struct Target {
+    pub x_min: i32,
+    pub x_max: i32,
+    pub y_min: i32,
+    pub y_max: i32,
+}
+
+// Get the two ends of a "min..max" range sliced out of the given line
+fn parse_range(range_str: &str, line: &str) -> AocResult<(i32, i32)> {
+    let range_ends = range_str.split("..").collect::<Vec<&str>>();
+
+    if range_ends.len() != 2 {
+        return Err(AocError::parse(1, error::column_of(line, range_str), format!("expected a range as min..max, found \"{}\"", range_str)));
+    }
+
+    return Ok((error::parse_token::<i32>(range_ends[0], line, 1)?, error::parse_token::<i32>(range_ends[1], line, 1)?));
+}
+
+// Get target coordinates
+pub fn parse_target(input: &str) -> AocResult<Target> {
+    let launch_input = match input.lines().next() {
+        Some(line) => line,
+        None => return Err(AocError::Input("no target area".to_string())),
+    };
+
+    let launch_values = match launch_input.strip_prefix("target area: x=") {
+        Some(ranges) => ranges.split(", y=").collect::<Vec<&str>>(),
+        None => return Err(AocError::parse(1, 1, "expected target area: x=min..max, y=min..max")),
+    };
+
+    if launch_values.len() != 2 {
+        return Err(AocError::parse(1, 1, "expected target area: x=min..max, y=min..max"));
+    }
+
+    let (x_min, x_max) = parse_range(launch_values[0], launch_input)?;
+    let (y_min, y_max) = parse_range(launch_values[1], launch_input)?;
+
+    // Velocities are only searched for targets ahead of and below the launcher
+    if x_min <= 0 || x_max < x_min {
+        return Err(AocError::parse(1, error::column_of(launch_input, launch_values[0]), "x range must be positive with min at most max"));
+    }
+
+    if y_max >= 0 || y_max < y_min {
+        return Err(AocError::parse(1, error::column_of(launch_input, launch_values[1]), "y range must be negative with min at most max"));
+    }
+
+    return Ok(Target {
+        x_min,
+        x_max,
+        y_min,
+        y_max,
+    });
+}
+
+pub fn is_on_target(x: i32, y: i32, target: &Target) -> bool{
+    return x >= target.x_min && x <= target.x_max && y >= target.y_min && y <= target.y_max;
+}
+
+// Returns max height if target hit, 0 otherwise
+pub fn get_max_height(initial_x_vel: i32, initial_y_vel: i32, target: &Target) -> i32 {
+    let mut x_vel = initial_x_vel;
+    let mut y_vel = initial_y_vel;
+    let mut x = 0;
+    let mut y = 0;
+    let mut max_height = 0;
+
+    loop {
+        if is_on_target(x, y, target) {
+            return max_height;
+        }
+
+        if y < target.y_min {
+            return 0;
+        }
+
+        x += x_vel;
+        y += y_vel;
+
+        if y > max_height {
+            max_height = y;
+        }
+
+        if x_vel > 0 {
+            x_vel -= 1;
+        }
+        else if x_vel < 0 {
+            x_vel += 1;
+        }
+
+        y_vel -= 1;
+    }
+
+}
+
+// Returns true if the probe hits the target
+pub fn probe_on_target(initial_x_vel: i32, initial_y_vel: i32, target: &Target) -> bool {
+    let mut x_vel = initial_x_vel;
+    let mut y_vel = initial_y_vel;
+    let mut x = 0;
+    let mut y = 0;
+
+    loop {
+        if is_on_target(x, y, target) {
+            return true;
+        }
+
+        if y < target.y_min {
+            return false;
+        }
+
+        x += x_vel;
+        y += y_vel;
+
+        if x_vel > 0 {
+            x_vel -= 1;
+        }
+        else if x_vel < 0 {
+            x_vel += 1;
+        }
+
+        y_vel -= 1;
+    }
+
+}
+
+// Determine valid trajectory with highest max height
+pub fn find_max_height(target: &Target) -> i32 {
+    let mut max_height = 0;
+
+    for x_vel in 0..target.x_max {
+        for y_vel in target.y_min..(-2 * target.y_min) {
+            let max_round_height = get_max_height(x_vel, y_vel, target);
+
+            if max_round_height > max_height {
+                max_height = max_round_height;
+            }
+        }
+    }
+
+    return max_height;
+}
+
+// Count every initial velocity that hits the target
+pub fn count_on_target(target: &Target) -> u32 {
+    let mut on_target_count = 0;
+
+    for x_vel in 0..2*target.x_max {
+        for y_vel in target.y_min..(-2 * target.y_min) {
+            if probe_on_target(x_vel, y_vel, target) {
+                on_target_count += 1;
+            }
+        }
+    }
+
+    return on_target_count;
+}
+
+pub fn part1(input: &str) -> AocResult<i32> {
+    return Ok(find_max_height(&parse_target(input)?));
+}
+
+pub fn part2(input: &str) -> AocResult<u32> {
+    return Ok(count_on_target(&parse_target(input)?));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day17.txt");
+
+    #[test]
+    fn launches_probes() {
+        let target = parse_target(EXAMPLE).unwrap();
+        assert!(probe_on_target(7, 2, &target));
+        assert!(probe_on_target(6, 9, &target));
+        assert!(!probe_on_target(17, -4, &target));
+        assert_eq!(45, get_max_height(6, 9, &target));
+    }
+
+    #[test]
+    fn searches_velocities() {
+        let target = parse_target(EXAMPLE).unwrap();
+        assert_eq!(45, find_max_height(&target));
+        assert_eq!(112, count_on_target(&target));
+    }
+
+    #[test]
+    fn rejects_targets_above_launcher() {
+        assert!(matches!(parse_target("target area: x=20..30, y=5..10"), Err(AocError::Parse { line: 1, .. })));
+        assert!(matches!(parse_target("target: x=20..30, y=-10..-5"), Err(AocError::Parse { line: 1, column: 1, .. })));
+    }
+}
@@ -0,0 +1,124 @@
+// Day 2: Dive!
+
+use crate::error::{self, AocError, AocResult};
+
+pub struct Position {
+    pub horizontal: i32,
+    pub depth: i32,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Direction {
+    Forward,
+    Down,
+    Up,
+}
+
+pub struct Command {
+    pub direction: Direction,
+    pub value: i32,
+}
+
+pub fn parse_commands(input: &str) -> AocResult<Vec<Command>> {
+    let mut commands = Vec::new();
+
+    for (line_num, line) in error::numbered_lines(input) {
+        let position_vector = line.split(" ").collect::<Vec<&str>>();
+
+        if position_vector.len() != 2 {
+            return Err(AocError::parse(line_num, 1, "expected a direction and a value"));
+        }
+
+        let direction = match position_vector[0] {
+            "forward" => Direction::Forward,
+            "down" => Direction::Down,
+            "up" => Direction::Up,
+            _ => return Err(AocError::parse(line_num, 1, format!("bad position \"{}\"", position_vector[0]))),
+        };
+
+        commands.push(Command {
+            direction,
+            value: error::parse_token::<i32>(position_vector[1], line, line_num)?,
+        });
+    }
+
+    return Ok(commands);
+}
+
+pub fn dive_position(commands: &[Command]) -> Position {
+    let mut horizontal_pos = 0;
+    let mut vertical_pos = 0;
+
+    for command in commands {
+        let int_value = command.value;
+
+        match command.direction {
+            Direction::Forward=>horizontal_pos+=int_value,
+            Direction::Down=>vertical_pos+=int_value,
+            Direction::Up=>vertical_pos-=int_value,
+        }
+    }
+
+    return Position {
+        horizontal: horizontal_pos,
+        depth: vertical_pos,
+    };
+}
+
+pub fn dive_aim(commands: &[Command]) -> Position {
+    let mut horizontal_pos = 0;
+    let mut vertical_pos = 0;
+    let mut aim = 0;
+
+    for command in commands {
+        let int_value = command.value;
+
+        match command.direction {
+            Direction::Forward=>{
+                horizontal_pos+=int_value;
+                vertical_pos+=aim*int_value;
+            },
+            Direction::Down=>aim+=int_value,
+            Direction::Up=>aim-=int_value,
+        }
+    }
+
+    return Position {
+        horizontal: horizontal_pos,
+        depth: vertical_pos,
+    };
+}
+
+pub fn part1(input: &str) -> AocResult<i32> {
+    let position = dive_position(&parse_commands(input)?);
+    return Ok(position.horizontal * position.depth);
+}
+
+pub fn part2(input: &str) -> AocResult<i32> {
+    let position = dive_aim(&parse_commands(input)?);
+    return Ok(position.horizontal * position.depth);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day02.txt");
+
+    #[test]
+    fn dives() {
+        let commands = parse_commands(EXAMPLE).unwrap();
+
+        let position = dive_position(&commands);
+        assert_eq!((15, 10), (position.horizontal, position.depth));
+
+        let position = dive_aim(&commands);
+        assert_eq!((15, 60), (position.horizontal, position.depth));
+    }
+
+    #[test]
+    fn reports_bad_direction() {
+        assert_eq!(AocError::parse(2, 1, "bad position \"back\""), parse_commands("up 1\nback 2\n").err().unwrap());
+        assert_eq!(AocError::parse(1, 4, "invalid number \"x\""), parse_commands("up x\n").err().unwrap());
+    }
+}
@@ -0,0 +1,2027 @@
+// Day 24: Arithmetic Logic Unit
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use crate::error::{self, AocError, AocResult};
+
+// The values an input can take unless told otherwise, which are the digits of a model number
+pub const DEFAULT_DOMAIN: Interval = Interval {min: 1, max: 9};
+
+// Every value of a domain is tried for each state when solving, so wider ones would never finish
+pub const MAX_DOMAIN_SIZE: i128 = 1000;
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum OpType {
+    Var,
+    Const,
+    Add,
+    Mul,
+    Div,
+    Mod,
+    Eql,
+    Inp,
+}
+
+pub struct Operation {
+    // The type of operation this is
+    pub op_type: OpType,
+
+    // The name of the variable / operation
+    pub name: char,
+
+    // The literal value of the node, used for Consts and when solving
+    pub val: i64,
+
+    // The input this reads, counting from 0 (Inp only)
+    pub input_digit: usize,
+
+    // The indecies of the child operations (Non Var and Const)
+    pub arg_a_index: usize,
+    pub arg_b_index: usize,
+
+    // The current head node index for a variable's calculation (Var only)
+    pub top: usize,
+}
+
+#[derive(Clone, Copy, Eq)]
+pub struct SolutionDigit {
+    pub used: bool,
+    pub value: i64,
+}
+
+impl PartialOrd for SolutionDigit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SolutionDigit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.used && other.used {
+            return self.value.cmp(&other.value);
+        }
+        else if !self.used && !other.used {
+            return Ordering::Equal;
+        }
+        else if self.used {
+            return Ordering::Less;
+        }
+        else {
+            return Ordering::Greater;
+        }
+    }
+}
+
+impl PartialEq for SolutionDigit {
+    fn eq(&self, other: &Self) -> bool {
+        self.used && other.used && self.value == other.value
+    }
+}
+
+#[derive(Clone, Eq)]
+pub struct Solution {
+    pub result: i64,
+
+    // One for each input the program reads
+    pub digits: Vec<SolutionDigit>,
+}
+
+impl PartialOrd for Solution {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Solution {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.result == other.result {
+            for i in 0..self.digits.len().min(other.digits.len()) {
+                if self.digits[i] != other.digits[i] {
+                    if self.digits[i].used && other.digits[i].used {
+                        return self.digits[i].cmp(&other.digits[i]);
+                    }
+                    else if self.digits[i].used || other.digits[i].used {
+                        return Ordering::Equal;
+                    }
+                }
+            }
+
+            return Ordering::Equal;
+        }
+        else {
+            return self.result.cmp(&other.result);
+        }
+    }
+}
+
+impl PartialEq for Solution {
+    fn eq(&self, other: &Self) -> bool {
+        if self.result == other.result {
+            if self.digits.len() != other.digits.len() {
+                return false;
+            }
+
+            for i in 0..self.digits.len() {
+                if self.digits[i] != other.digits[i] {
+                    return false;
+                }
+            }
+            return true;
+        }
+        else {
+            return false;
+        }
+    }
+}
+
+pub fn get_operation_index_of(arg: char, vars: &[Operation]) -> AocResult<usize> {
+    // Get the most recently added constant
+    if arg == '-' {
+        for i in (0..vars.len()).rev() {
+            if vars[i].op_type == OpType::Const {
+                return Ok(i);
+            }
+        }
+    }
+
+    // Get the associated variable's top index
+    else {
+        for i in 0..vars.len() {
+            if vars[i].op_type == OpType::Var && vars[i].name == arg {
+                return Ok(vars[i].top);
+            }
+        }
+    }
+
+    return Err(AocError::Input(format!("unknown variable '{}'", arg)));
+}
+
+pub fn set_top_to_latest(arg: char, vars: &mut [Operation]) {
+    for i in 0..vars.len() {
+        if vars[i].op_type == OpType::Var && vars[i].name == arg {
+            // Set new top to end of vector
+            vars[i].top = vars.len();
+            break;
+        }
+    }
+}
+
+pub fn alu_op(arg_a: char, arg_b: char, op_type: OpType, name: char, vars: &mut Vec<Operation>) -> AocResult<()> {
+    let idx_a = get_operation_index_of(arg_a, vars)?;
+    let idx_b = get_operation_index_of(arg_b, vars)?;
+
+    // Dividing by a constant zero would crash when solving
+    if (op_type == OpType::Div || op_type == OpType::Mod) && vars[idx_b].op_type == OpType::Const && vars[idx_b].val == 0 {
+        return Err(AocError::Input("division by zero".to_string()));
+    }
+
+    // Everything else is left for optimize_operations, so the operations match the program one to one
+    set_top_to_latest(arg_a, vars);
+
+    vars.push(Operation {
+        op_type,
+        name,
+        val: 0,
+        input_digit: 0,
+        arg_a_index: idx_a,
+        arg_b_index: idx_b,
+        top: 0,
+    });
+
+    return Ok(());
+}
+
+pub fn alu_add_op(arg_a: char, arg_b: char, vars: &mut Vec<Operation>) -> AocResult<()> {
+    return alu_op(arg_a, arg_b, OpType::Add, '+', vars);
+}
+
+pub fn alu_mul_op(arg_a: char, arg_b: char, vars: &mut Vec<Operation>) -> AocResult<()> {
+    return alu_op(arg_a, arg_b, OpType::Mul, '*', vars);
+}
+
+pub fn alu_div_op(arg_a: char, arg_b: char, vars: &mut Vec<Operation>) -> AocResult<()> {
+    return alu_op(arg_a, arg_b, OpType::Div, '/', vars);
+}
+
+pub fn alu_mod_op(arg_a: char, arg_b: char, vars: &mut Vec<Operation>) -> AocResult<()> {
+    return alu_op(arg_a, arg_b, OpType::Mod, '%', vars);
+}
+
+pub fn alu_eql_op(arg_a: char, arg_b: char, vars: &mut Vec<Operation>) -> AocResult<()> {
+    return alu_op(arg_a, arg_b, OpType::Eql, '=', vars);
+}
+
+pub fn alu_inp_op(arg_a: char, digit_num: usize, vars: &mut Vec<Operation>) {
+    set_top_to_latest(arg_a, vars);
+
+    vars.push(Operation {
+        op_type: OpType::Inp,
+        name: ':',
+        val: 0,
+        input_digit: digit_num,
+        arg_a_index: 0,
+        arg_b_index: 0,
+        top: 0,
+    });
+}
+
+// Return the next digit num if inp otherwise current digit num
+pub fn alu_inst(instruction: &str, digit_num: usize, vars: &mut Vec<Operation>) -> AocResult<usize> {
+    let args = instruction.split_whitespace().collect::<Vec<&str>>();
+
+    if args.is_empty() {
+        return Err(AocError::Input("empty instruction".to_string()));
+    }
+
+    let num_args = if args[0] == "inp" {2} else {3};
+
+    if args.len() != num_args {
+        return Err(AocError::Input(format!("expected {} arguments for {}, found {}", num_args - 1, args[0], args.len() - 1)));
+    }
+
+    if !["w", "x", "y", "z"].contains(&args[1]) {
+        return Err(AocError::Input(format!("bad variable \"{}\"", args[1])));
+    }
+
+    let arg_a = args[1].as_bytes()[0] as char;
+
+    let mut arg_b = '-';
+
+    if args.len() > 2 {
+        // 2nd arg is an immediate value, not a variable, create a temp var to reflect this
+        if ["w", "x", "y", "z"].contains(&args[2]) {
+            arg_b = args[2].as_bytes()[0] as char;
+        }
+        else {
+            let val = match args[2].parse::<i64>() {
+                Ok(val) => val,
+                Err(_) => return Err(AocError::Input(format!("bad variable or number \"{}\"", args[2]))),
+            };
+
+            vars.push(Operation {
+                op_type: OpType::Const,
+                name: '-',
+                val,
+                input_digit: 0,
+                arg_a_index: 0,
+                arg_b_index: 0,
+                top: 0,
+            });
+        }
+    }
+
+    match args[0] {
+        "add" => alu_add_op(arg_a, arg_b, vars)?,
+        "mul" => alu_mul_op(arg_a, arg_b, vars)?,
+        "div" => alu_div_op(arg_a, arg_b, vars)?,
+        "mod" => alu_mod_op(arg_a, arg_b, vars)?,
+        "eql" => alu_eql_op(arg_a, arg_b, vars)?,
+        "inp" => {
+            alu_inp_op(arg_a, digit_num, vars);
+            return Ok(digit_num + 1);
+        }
+        _ => return Err(AocError::Input(format!("invalid command \"{}\"", args[0]))),
+    }
+
+    return Ok(digit_num);
+}
+
+// Write out the calculation of a node one operation per line, as t<index> = <argument> <operator> <argument>
+// Each operation is written once however many others use it, finishing with the node given the name
+pub fn alu_listing(name: char, index: usize, vars: &[Operation]) -> String {
+    let operand = |index: usize| {
+        match vars[index].op_type {
+            OpType::Const => return vars[index].val.to_string(),
+            OpType::Var => return vars[index].name.to_string(),
+            OpType::Inp => return format!("d{}", vars[index].input_digit),
+            _ => return format!("t{}", index),
+        }
+    };
+
+    // Arguments always come before the operations using them, so one pass back from the node finds everything it uses
+    let mut used = vec![false; vars.len()];
+    used[index] = true;
+
+    for i in (0..=index).rev() {
+        if used[i] && !matches!(vars[i].op_type, OpType::Var | OpType::Const | OpType::Inp) {
+            used[vars[i].arg_a_index] = true;
+            used[vars[i].arg_b_index] = true;
+        }
+    }
+
+    let mut listing = String::new();
+
+    for i in 0..=index {
+        if used[i] && !matches!(vars[i].op_type, OpType::Var | OpType::Const | OpType::Inp) {
+            listing.push_str(&format!("t{} = {} {} {}\n", i, operand(vars[i].arg_a_index), vars[i].name, operand(vars[i].arg_b_index)));
+        }
+    }
+
+    listing.push_str(&format!("{} = {}\n", name, operand(index)));
+    return listing;
+}
+
+pub fn alu_print(name: char, index: usize, vars: &[Operation]) {
+    eprint!("{}", alu_listing(name, index, vars));
+}
+
+pub fn create_empty_solution(result: i64, num_inputs: usize) -> Solution {
+    return Solution {
+        result,
+        digits: vec![SolutionDigit {
+                used: false,
+                value: 0,
+        }; num_inputs],
+    };
+}
+
+// The number of inputs a program reads, one for each inp instruction
+pub fn count_inputs(vars: &[Operation]) -> usize {
+    return vars.iter().filter(|var| var.op_type == OpType::Inp).count();
+}
+
+// The default domain for every input the program reads
+pub fn default_domains(vars: &[Operation]) -> Vec<Interval> {
+    return vec![DEFAULT_DOMAIN; count_inputs(vars)];
+}
+
+// Read the values each input can take, written as MIN..MAX ranges split by commas
+// A single range applies to every input, otherwise there must be one for each
+pub fn parse_domains(text: &str, num_inputs: usize) -> AocResult<Vec<Interval>> {
+    let mut domains = Vec::<Interval>::new();
+
+    for range in text.split(',') {
+        let range = range.trim();
+        let column = error::column_of(text, range);
+
+        let (min, max) = match range.split_once("..") {
+            Some((min, max)) => (min.trim().parse::<i64>(), max.trim().parse::<i64>()),
+            None => return Err(AocError::parse(1, column, format!("expected MIN..MAX, found \"{}\"", range))),
+        };
+
+        let domain = match (min, max) {
+            (Ok(min), Ok(max)) => Interval {min, max},
+            _ => return Err(AocError::parse(1, column, format!("bad domain \"{}\"", range))),
+        };
+
+        if domain.min > domain.max {
+            return Err(AocError::parse(1, column, format!("domain {} is empty", range)));
+        }
+
+        if domain.size() > MAX_DOMAIN_SIZE {
+            return Err(AocError::parse(1, column, format!("domain {} has more than {} values", range, MAX_DOMAIN_SIZE)));
+        }
+
+        domains.push(domain);
+    }
+
+    if domains.len() == 1 {
+        return Ok(vec![domains[0]; num_inputs]);
+    }
+
+    if domains.len() != num_inputs {
+        return Err(AocError::Input(format!("expected 1 or {} domains, found {}", num_inputs, domains.len())));
+    }
+
+    return Ok(domains);
+}
+
+// Which accepted model number to look for
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Preference {
+    Largest,
+    Smallest,
+}
+
+impl Preference {
+    // Whether a solution is wanted over another with the same result
+    pub fn prefers(self, solution: &Solution, other: &Solution) -> bool {
+        match self {
+            Preference::Largest => return solution > other,
+            Preference::Smallest => return solution < other,
+        }
+    }
+
+    // The value to use from an input's domain where the input makes no difference
+    pub fn unused_digit(self, domain: Interval) -> i64 {
+        match self {
+            Preference::Largest => return domain.max,
+            Preference::Smallest => return domain.min,
+        }
+    }
+}
+
+// If combination of two solutions succeeds return it and true otherwise false
+pub fn combine_solutions(result: i64, solution_a: &Solution, solution_b: &Solution) -> (Solution, bool) {
+    let mut new_solution = create_empty_solution(result, solution_a.digits.len().max(solution_b.digits.len()));
+
+    for i in 0..new_solution.digits.len() {
+        let digit_a = solution_a.digits.get(i).filter(|digit| digit.used);
+        let digit_b = solution_b.digits.get(i).filter(|digit| digit.used);
+
+        match (digit_a, digit_b) {
+            (Some(digit_a), Some(digit_b)) if digit_a.value != digit_b.value => return (new_solution, false),
+            (Some(digit), _) | (None, Some(digit)) => new_solution.digits[i] = *digit,
+            (None, None) => {},
+        }
+    }
+
+    return (new_solution, true);
+}
+
+// Keep the preferred solution per output value, leaving them sorted
+pub fn trim_solutions(solutions: &mut Vec<Solution>, preference: Preference) {
+    solutions.sort();
+
+    if preference == Preference::Largest {
+        solutions.reverse();
+    }
+
+    solutions.dedup_by_key(|solution| solution.result);
+    solutions.sort();
+}
+
+// The smallest and largest values something can have
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Interval {
+    pub min: i64,
+    pub max: i64,
+}
+
+impl Interval {
+    pub fn exactly(val: i64) -> Interval {
+        return Interval {
+            min: val,
+            max: val,
+        };
+    }
+
+    pub fn contains(&self, val: i64) -> bool {
+        return self.min <= val && val <= self.max;
+    }
+
+    // The number of values in the interval, which does not fit in 64 bits for the widest ones
+    pub fn size(&self) -> i128 {
+        return (self.max as i128 - self.min as i128 + 1).max(0);
+    }
+
+    // Build from wider bounds, clamping anything that does not fit in 64 bits
+    fn from_wide(min: i128, max: i128) -> Interval {
+        return Interval {
+            min: min.clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+            max: max.clamp(i64::MIN as i128, i64::MAX as i128) as i64,
+        };
+    }
+
+    // The interval covering every value from applying op to the corners of the two intervals,
+    // which holds everything in between for operations that only ever rise or fall along each argument
+    fn from_corners(a: Interval, b: Interval, op: impl Fn(i128, i128) -> i128) -> Interval {
+        let corners = [op(a.min as i128, b.min as i128), op(a.min as i128, b.max as i128), op(a.max as i128, b.min as i128), op(a.max as i128, b.max as i128)];
+        return Interval::from_wide(*corners.iter().min().unwrap(), *corners.iter().max().unwrap());
+    }
+}
+
+// Apply an operation to two values, giving None if the ALU could not carry it out
+pub fn apply_op(op_type: OpType, a: i64, b: i64) -> Option<i64> {
+    match op_type {
+        OpType::Add => return a.checked_add(b),
+        OpType::Mul => return a.checked_mul(b),
+        OpType::Div => return if b == 0 {None} else {a.checked_div(b)},
+        OpType::Mod => return if a < 0 || b <= 0 {None} else {Some(a % b)},
+        OpType::Eql => return Some(if a == b {1} else {0}),
+        OpType::Var | OpType::Const | OpType::Inp => return None,
+    }
+}
+
+// Work out every value an operation can give for arguments in the two intervals
+// Runs the ALU could not carry out are left out, as they never lead to a valid model number
+pub fn apply_op_interval(op_type: OpType, a: Interval, b: Interval) -> Interval {
+    match op_type {
+        OpType::Add => return Interval::from_wide(a.min as i128 + b.min as i128, a.max as i128 + b.max as i128),
+        OpType::Mul => return Interval::from_corners(a, b, |a, b| a * b),
+        OpType::Div => {
+            // Division rises or falls along each argument as long as the divisor keeps one sign, so split it around 0
+            let negative = Interval {min: b.min, max: b.max.min(-1)};
+            let positive = Interval {min: b.min.max(1), max: b.max};
+
+            return match (negative.min <= negative.max, positive.min <= positive.max) {
+                (true, true) => {
+                    let negative = Interval::from_corners(a, negative, |a, b| a / b);
+                    let positive = Interval::from_corners(a, positive, |a, b| a / b);
+                    Interval {min: negative.min.min(positive.min), max: negative.max.max(positive.max)}
+                },
+                (true, false) => Interval::from_corners(a, negative, |a, b| a / b),
+                (false, true) => Interval::from_corners(a, positive, |a, b| a / b),
+                (false, false) => a,
+            };
+        },
+        OpType::Mod => {
+            let a = Interval {min: a.min.max(0), max: a.max};
+            let b = Interval {min: b.min.max(1), max: b.max};
+
+            if a.max < b.min {
+                return a;
+            }
+
+            return Interval {min: 0, max: a.max.min(b.max - 1).max(0)};
+        },
+        OpType::Eql => {
+            if a.max < b.min || b.max < a.min {
+                return Interval::exactly(0);
+            }
+
+            if a.min == a.max && a == b {
+                return Interval::exactly(1);
+            }
+
+            return Interval {min: 0, max: 1};
+        },
+        OpType::Var | OpType::Const | OpType::Inp => return a,
+    }
+}
+
+// Where a value needed while working out a stage comes from
+#[derive(Clone, Copy, Debug)]
+enum StageSource {
+    Const(i64),
+
+    // A node worked out in an earlier stage, by its position in the state
+    State(usize),
+
+    // A node worked out earlier in this stage, by its position in the stage's steps
+    Step(usize),
+    Digit,
+}
+
+// Everything to work out once a digit is known, taking the state left by the previous stage to the next one
+//
+// A state holds the values of the nodes worked out so far that are still needed, so two partial model numbers
+// reaching the same state finish the same way and only the preferred one has to be kept
+struct Stage {
+    // Operations in an order where arguments come before the operations using them
+    steps: Vec<(OpType, StageSource, StageSource)>,
+    next_state: Vec<StageSource>,
+    uses_digit: bool,
+}
+
+// How a node's value comes about
+enum Staged {
+    // The stages to work through, and the node's value if it does not depend on any digit
+    Stages(Vec<Stage>, Option<i64>),
+
+    // The node does not depend on any digit
+    Always(i64),
+
+    // Working out the node always fails, whatever the digits
+    Never,
+}
+
+// Split the calculation of a node into one stage per digit
+// Every operation that might fail for inputs in the domains is worked out too, so runs where the program fails are dropped
+fn build_stages(index: usize, vars: &[Operation], domains: &[Interval]) -> Staged {
+    let num_digits = count_inputs(vars);
+    let intervals = operation_intervals(vars, domains);
+
+    // Arguments always come before the operations using them, so one pass back from the node finds everything it needs
+    let mut needed = vec![false; vars.len()];
+    needed[index] = true;
+
+    for i in 0..vars.len() {
+        if !matches!(vars[i].op_type, OpType::Var | OpType::Const | OpType::Inp) {
+            needed[i] = needed[i] || may_fail(vars[i].op_type, intervals[vars[i].arg_a_index], intervals[vars[i].arg_b_index]);
+        }
+    }
+
+    for i in (0..vars.len()).rev() {
+        if needed[i] && !matches!(vars[i].op_type, OpType::Var | OpType::Const | OpType::Inp) {
+            needed[vars[i].arg_a_index] = true;
+            needed[vars[i].arg_b_index] = true;
+        }
+    }
+
+    // The last digit each node depends on, and the value of nodes that do not depend on any
+    let mut last_digit: Vec<Option<usize>> = vec![None; vars.len()];
+    let mut const_val = vec![0; vars.len()];
+    let mut const_fails = false;
+
+    for i in 0..vars.len() {
+        match vars[i].op_type {
+            OpType::Var | OpType::Const => const_val[i] = vars[i].val,
+            OpType::Inp => last_digit[i] = Some(vars[i].input_digit),
+            op_type => {
+                let (arg_a, arg_b) = (vars[i].arg_a_index, vars[i].arg_b_index);
+                last_digit[i] = last_digit[arg_a].max(last_digit[arg_b]);
+
+                // A constant operation the ALU cannot carry out stops every run
+                if needed[i] && last_digit[i].is_none() {
+                    match apply_op(op_type, const_val[arg_a], const_val[arg_b]) {
+                        Some(val) => const_val[i] = val,
+                        None => const_fails = true,
+                    }
+                }
+            },
+        }
+    }
+
+    if const_fails {
+        return Staged::Never;
+    }
+
+    let root_const = if last_digit[index].is_none() {Some(const_val[index])} else {None};
+
+    if root_const.is_some() && (0..vars.len()).all(|i| !needed[i] || last_digit[i].is_none()) {
+        return Staged::Always(const_val[index]);
+    }
+
+    // The stage after which each node is no longer needed, with the node itself needed until the end
+    // Operations only worked out in case they fail are not needed after their own stage
+    let mut needed_until = vec![0; vars.len()];
+    needed_until[index] = num_digits;
+
+    for i in 0..vars.len() {
+        if needed[i] && !matches!(vars[i].op_type, OpType::Var | OpType::Const | OpType::Inp) {
+            if let Some(digit) = last_digit[i] {
+                needed_until[vars[i].arg_a_index] = needed_until[vars[i].arg_a_index].max(digit);
+                needed_until[vars[i].arg_b_index] = needed_until[vars[i].arg_b_index].max(digit);
+            }
+        }
+    }
+
+    // The nodes held in the state before each stage
+    let state_nodes = |stage: usize| (0..vars.len()).filter(|i| needed[*i] && last_digit[*i].is_some_and(|digit| digit < stage) && needed_until[*i] >= stage).collect::<Vec<usize>>();
+
+    let mut stages = Vec::<Stage>::new();
+    let mut positions = vec![0; vars.len()];
+    let mut current_state = state_nodes(0);
+
+    for digit in 0..num_digits {
+        for (position, node) in current_state.iter().enumerate() {
+            positions[*node] = position;
+        }
+
+        let mut stage = Stage {
+            steps: Vec::<(OpType, StageSource, StageSource)>::new(),
+            next_state: Vec::<StageSource>::new(),
+            uses_digit: false,
+        };
+
+        let source = |node: usize, positions: &[usize]| {
+            match (last_digit[node], vars[node].op_type) {
+                (None, _) => return StageSource::Const(const_val[node]),
+                (Some(_), OpType::Inp) if last_digit[node] == Some(digit) => return StageSource::Digit,
+                (Some(node_digit), _) if node_digit < digit => return StageSource::State(positions[node]),
+                _ => return StageSource::Step(positions[node]),
+            }
+        };
+
+        for i in 0..vars.len() {
+            if !needed[i] || last_digit[i] != Some(digit) {
+                continue;
+            }
+
+            if vars[i].op_type == OpType::Inp {
+                stage.uses_digit = true;
+                continue;
+            }
+
+            let step = (vars[i].op_type, source(vars[i].arg_a_index, &positions), source(vars[i].arg_b_index, &positions));
+            positions[i] = stage.steps.len();
+            stage.steps.push(step);
+        }
+
+        let next_state = state_nodes(digit + 1);
+
+        for node in &next_state {
+            stage.next_state.push(source(*node, &positions));
+        }
+
+        stages.push(stage);
+        current_state = next_state;
+    }
+
+    return Staged::Stages(stages, root_const);
+}
+
+// Work out a stage for one digit, giving None if the ALU could not carry it out
+fn run_stage(stage: &Stage, state: &[i64], digit: i64, step_vals: &mut Vec<i64>) -> Option<Vec<i64>> {
+    let val_of = |source: StageSource, step_vals: &[i64]| {
+        match source {
+            StageSource::Const(val) => return val,
+            StageSource::State(position) => return state[position],
+            StageSource::Step(position) => return step_vals[position],
+            StageSource::Digit => return digit,
+        }
+    };
+
+    step_vals.clear();
+
+    for (op_type, arg_a, arg_b) in &stage.steps {
+        let val = apply_op(*op_type, val_of(*arg_a, step_vals), val_of(*arg_b, step_vals))?;
+        step_vals.push(val);
+    }
+
+    return Some(stage.next_state.iter().map(|source| val_of(*source, step_vals)).collect());
+}
+
+// Check whether finishing the remaining stages from a state could give the target, with a domain for each stage's digit
+// Without a target every state is kept
+fn can_reach(stages: &[Stage], domains: &[Interval], state: &[i64], target: Option<i64>) -> bool {
+    let target = match target {
+        Some(target) => target,
+        None => return true,
+    };
+
+    let mut state = state.iter().map(|val| Interval::exactly(*val)).collect::<Vec<Interval>>();
+    let mut step_intervals = Vec::<Interval>::new();
+
+    for (stage, digit) in stages.iter().zip(domains) {
+        let digit = *digit;
+
+        let interval_of = |source: StageSource, step_intervals: &[Interval]| {
+            match source {
+                StageSource::Const(val) => return Interval::exactly(val),
+                StageSource::State(position) => return state[position],
+                StageSource::Step(position) => return step_intervals[position],
+                StageSource::Digit => return digit,
+            }
+        };
+
+        step_intervals.clear();
+
+        for (op_type, arg_a, arg_b) in &stage.steps {
+            let interval = apply_op_interval(*op_type, interval_of(*arg_a, &step_intervals), interval_of(*arg_b, &step_intervals));
+            step_intervals.push(interval);
+        }
+
+        state = stage.next_state.iter().map(|source| interval_of(*source, &step_intervals)).collect();
+    }
+
+    return state[0].contains(target);
+}
+
+// Find the preferred digits giving each result a node can have, searching a digit at a time
+// Each input takes the values in its domain, and partial model numbers that can no longer give the target
+// or that make any operation in the program fail are dropped along the way
+pub fn alu_any_solve(index: usize, vars: &[Operation], domains: &[Interval], target: i64, preference: Preference) -> AocResult<Vec<Solution>> {
+    let num_inputs = count_inputs(vars);
+
+    if domains.len() != num_inputs {
+        return Err(AocError::Input(format!("expected {} domains, given {}", num_inputs, domains.len())));
+    }
+
+    if let Some(domain) = domains.iter().find(|domain| domain.size() > MAX_DOMAIN_SIZE) {
+        return Err(AocError::Input(format!("domain {}..{} has more than {} values", domain.min, domain.max, MAX_DOMAIN_SIZE)));
+    }
+
+    let (stages, root_const) = match build_stages(index, vars, domains) {
+        Staged::Stages(stages, root_const) => (stages, root_const),
+        Staged::Always(result) => return Ok(vec![create_empty_solution(result, num_inputs)]),
+        Staged::Never => return Ok(Vec::<Solution>::new()),
+    };
+
+    // A node that is the same whatever the digits cannot be steered towards the target, only the failures need avoiding
+    let stage_target = if root_const.is_some() {None} else {Some(target)};
+
+    let mut states = HashMap::<Vec<i64>, Solution>::new();
+    states.insert(Vec::<i64>::new(), create_empty_solution(0, num_inputs));
+
+    let mut step_vals = Vec::<i64>::new();
+
+    for (digit_index, stage) in stages.iter().enumerate() {
+        let mut next_states = HashMap::<Vec<i64>, Solution>::new();
+        let domain = domains[digit_index];
+        let digit_vals = if stage.uses_digit {domain.min..=domain.max} else {domain.min..=domain.min};
+
+        for (state, solution) in &states {
+            for digit in digit_vals.clone() {
+                let next_state = match run_stage(stage, state, digit, &mut step_vals) {
+                    Some(next_state) => next_state,
+                    None => continue,
+                };
+
+                if next_states.get(&next_state).is_some_and(|existing| !preference.prefers(&with_digit(solution, digit_index, digit, stage), existing)) {
+                    continue;
+                }
+
+                if !can_reach(&stages[digit_index + 1..], &domains[digit_index + 1..], &next_state, stage_target) {
+                    continue;
+                }
+
+                next_states.insert(next_state, with_digit(solution, digit_index, digit, stage));
+            }
+        }
+
+        states = next_states;
+    }
+
+    // The final state is the node's value, unless it is a constant
+    let mut solutions = Vec::<Solution>::new();
+
+    for (state, mut solution) in states {
+        solution.result = root_const.unwrap_or_else(|| state[0]);
+        solutions.push(solution);
+    }
+
+    trim_solutions(&mut solutions, preference);
+    return Ok(solutions);
+}
+
+// Copy a solution with a digit filled in, if the stage uses it
+fn with_digit(solution: &Solution, digit_index: usize, digit: i64, stage: &Stage) -> Solution {
+    let mut new_solution = solution.clone();
+
+    if stage.uses_digit {
+        new_solution.digits[digit_index] = SolutionDigit {
+            used: true,
+            value: digit,
+        };
+    }
+
+    return new_solution;
+}
+
+// How much an optimization pass shrank a set of operations
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub struct OptimizeStats {
+    pub nodes_before: usize,
+    pub nodes_after: usize,
+
+    // Operations worked out ahead of time to a constant
+    pub folded: usize,
+
+    // Operations giving one of their arguments unchanged, such as adding 0 or dividing by 1
+    pub simplified: usize,
+
+    // Operations the ranges of their arguments show to be constant or to do nothing, such as a digit mod 26
+    pub pruned: usize,
+
+    // Operations repeating one already done on the same arguments
+    pub merged: usize,
+
+    // Operations whose results are never used
+    pub dead: usize,
+}
+
+impl fmt::Display for OptimizeStats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        return write!(f, "{} nodes down to {}: {} folded, {} simplified, {} pruned, {} merged, {} dead",
+            self.nodes_before, self.nodes_after, self.folded, self.simplified, self.pruned, self.merged, self.dead);
+    }
+}
+
+// What an operation can be replaced with once its arguments are known
+enum Rewrite {
+    Const(i64),
+    Arg(usize),
+    Keep,
+}
+
+// Whether the ALU could fail to carry out an operation on arguments in the two intervals
+fn may_fail(op_type: OpType, a: Interval, b: Interval) -> bool {
+    let fits = |min: i128, max: i128| min >= i64::MIN as i128 && max <= i64::MAX as i128;
+
+    match op_type {
+        OpType::Add => return !fits(a.min as i128 + b.min as i128, a.max as i128 + b.max as i128),
+        OpType::Mul => {
+            let corners = [a.min as i128 * b.min as i128, a.min as i128 * b.max as i128, a.max as i128 * b.min as i128, a.max as i128 * b.max as i128];
+            return !fits(*corners.iter().min().unwrap(), *corners.iter().max().unwrap());
+        },
+        OpType::Div => return b.contains(0) || (a.min == i64::MIN && b.contains(-1)),
+        OpType::Mod => return a.min < 0 || b.min <= 0,
+        OpType::Eql | OpType::Var | OpType::Const => return false,
+
+        // Reading an input fails when there are none left
+        OpType::Inp => return true,
+    }
+}
+
+// Find a simpler replacement for an operation on two already optimized nodes
+// Replacements never drop a failure, so an operation that might fail is only replaced when it cannot fail for these arguments
+fn rewrite_operation(op_type: OpType, arg_a: usize, arg_b: usize, optimized: &[Operation], intervals: &[Interval]) -> Rewrite {
+    let const_of = |index: usize| if optimized[index].op_type == OpType::Const {Some(optimized[index].val)} else {None};
+    let (a, b) = (const_of(arg_a), const_of(arg_b));
+
+    if let (Some(a), Some(b)) = (a, b) {
+        return match apply_op(op_type, a, b) {
+            Some(val) => Rewrite::Const(val),
+            None => Rewrite::Keep,
+        };
+    }
+
+    match (op_type, a, b) {
+        (OpType::Add, _, Some(0)) => return Rewrite::Arg(arg_a),
+        (OpType::Add, Some(0), _) => return Rewrite::Arg(arg_b),
+        (OpType::Mul, _, Some(0)) | (OpType::Mul, Some(0), _) => return Rewrite::Const(0),
+        (OpType::Mul, _, Some(1)) => return Rewrite::Arg(arg_a),
+        (OpType::Mul, Some(1), _) => return Rewrite::Arg(arg_b),
+        (OpType::Div, _, Some(1)) => return Rewrite::Arg(arg_a),
+        (OpType::Mod, _, Some(1)) if intervals[arg_a].min >= 0 => return Rewrite::Const(0),
+        (OpType::Eql, _, _) if arg_a == arg_b => return Rewrite::Const(1),
+        _ => (),
+    }
+
+    return Rewrite::Keep;
+}
+
+// Find a replacement for an operation from the range of values each argument can have
+fn prune_operation(op_type: OpType, arg_a: usize, arg_b: usize, intervals: &[Interval]) -> Rewrite {
+    let (a, b) = (intervals[arg_a], intervals[arg_b]);
+
+    // Only ranges where the ALU cannot fail are looked at, so nothing is pruned that would have stopped the program
+    if may_fail(op_type, a, b) {
+        return Rewrite::Keep;
+    }
+
+    match op_type {
+        // Values that can never meet, such as a digit and 10, are never equal
+        OpType::Eql => {
+            let interval = apply_op_interval(OpType::Eql, a, b);
+
+            if interval.min == interval.max {
+                return Rewrite::Const(interval.min);
+            }
+        },
+
+        // A value already below the divisor is left as it is by mod and goes to 0 with div
+        OpType::Mod if a.max < b.min => return Rewrite::Arg(arg_a),
+        OpType::Div if a.min >= 0 && a.max < b.min => return Rewrite::Const(0),
+        OpType::Div if b == Interval::exactly(1) => return Rewrite::Arg(arg_a),
+        OpType::Mod if b == Interval::exactly(1) => return Rewrite::Const(0),
+        _ => (),
+    }
+
+    return Rewrite::Keep;
+}
+
+// Work out the range of values a node can have from the ranges of its arguments, with a domain for each input
+// Inputs without a domain are taken to be anything
+pub fn operation_interval(operation: &Operation, intervals: &[Interval], domains: &[Interval]) -> Interval {
+    match operation.op_type {
+        OpType::Var | OpType::Const => return Interval::exactly(operation.val),
+        OpType::Inp => return domains.get(operation.input_digit).copied().unwrap_or(Interval {min: i64::MIN, max: i64::MAX}),
+        op_type => return apply_op_interval(op_type, intervals[operation.arg_a_index], intervals[operation.arg_b_index]),
+    }
+}
+
+// Find the range of values every node can have given the domain of each input
+pub fn operation_intervals(vars: &[Operation], domains: &[Interval]) -> Vec<Interval> {
+    let mut intervals = Vec::<Interval>::with_capacity(vars.len());
+
+    for operation in vars {
+        let interval = operation_interval(operation, &intervals, domains);
+        intervals.push(interval);
+    }
+
+    return intervals;
+}
+
+// Add a node unless the same one is already there, giving its index and whether it was already there
+fn push_operation(operation: Operation, interval: Interval, optimized: &mut Vec<Operation>, intervals: &mut Vec<Interval>, existing: &mut HashMap<(OpType, i64, usize, usize, usize), usize>) -> (usize, bool) {
+    let key = (operation.op_type, operation.val, operation.input_digit, operation.arg_a_index, operation.arg_b_index);
+
+    if let Some(index) = existing.get(&key) {
+        return (*index, true);
+    }
+
+    existing.insert(key, optimized.len());
+    optimized.push(operation);
+    intervals.push(interval);
+
+    return (optimized.len() - 1, false);
+}
+
+// Rebuild the operations with constants folded, operations that do nothing skipped, repeated operations merged
+// and unused operations removed, using the domain of each input to prune comparisons, mods and divs by range
+// Unused operations that might fail are kept, as a failing instruction stops the whole program
+pub fn optimize_operations(vars: &[Operation], domains: &[Interval]) -> (Vec<Operation>, OptimizeStats) {
+    return optimize_operations_with(vars, domains, true);
+}
+
+// Optimize operations, only merging repeated operations if asked to
+// Without merging, no value has to be kept any longer than the original program kept it
+fn optimize_operations_with(vars: &[Operation], domains: &[Interval], merge: bool) -> (Vec<Operation>, OptimizeStats) {
+    let mut stats = OptimizeStats {
+        nodes_before: vars.iter().filter(|var| var.op_type != OpType::Var).count(),
+        ..OptimizeStats::default()
+    };
+
+    let mut optimized = Vec::<Operation>::new();
+    let mut intervals = Vec::<Interval>::new();
+    let mut existing = HashMap::<(OpType, i64, usize, usize, usize), usize>::new();
+    let mut new_index = vec![0; vars.len()];
+
+    for i in 0..vars.len() {
+        let operation = match vars[i].op_type {
+            OpType::Var => Operation {
+                op_type: OpType::Var,
+                name: vars[i].name,
+                val: 0,
+                input_digit: 0,
+                arg_a_index: 0,
+                arg_b_index: 0,
+                top: 0,
+            },
+            OpType::Const => Operation {
+                op_type: OpType::Const,
+                name: '-',
+                val: vars[i].val,
+                input_digit: 0,
+                arg_a_index: 0,
+                arg_b_index: 0,
+                top: 0,
+            },
+            OpType::Inp => Operation {
+                op_type: OpType::Inp,
+                name: vars[i].name,
+                val: 0,
+                input_digit: vars[i].input_digit,
+                arg_a_index: 0,
+                arg_b_index: 0,
+                top: 0,
+            },
+            op_type => {
+                let (mut arg_a, mut arg_b) = (new_index[vars[i].arg_a_index], new_index[vars[i].arg_b_index]);
+
+                let mut rewrite = rewrite_operation(op_type, arg_a, arg_b, &optimized, &intervals);
+                let pruned = matches!(rewrite, Rewrite::Keep);
+
+                if pruned {
+                    rewrite = prune_operation(op_type, arg_a, arg_b, &intervals);
+                }
+
+                match rewrite {
+                    Rewrite::Const(val) => {
+                        if pruned {stats.pruned += 1} else {stats.folded += 1}
+
+                        let operation = Operation {
+                            op_type: OpType::Const,
+                            name: '-',
+                            val,
+                            input_digit: 0,
+                            arg_a_index: 0,
+                            arg_b_index: 0,
+                            top: 0,
+                        };
+
+                        new_index[i] = push_operation(operation, Interval::exactly(val), &mut optimized, &mut intervals, &mut existing).0;
+                        continue;
+                    },
+                    Rewrite::Arg(index) => {
+                        if pruned {stats.pruned += 1} else {stats.simplified += 1}
+
+                        new_index[i] = index;
+                        continue;
+                    },
+                    Rewrite::Keep => (),
+                }
+
+                // Argument order does not matter for these, so put them in one order to find repeats
+                if matches!(op_type, OpType::Add | OpType::Mul | OpType::Eql) && arg_a > arg_b {
+                    (arg_a, arg_b) = (arg_b, arg_a);
+                }
+
+                Operation {
+                    op_type,
+                    name: vars[i].name,
+                    val: 0,
+                    input_digit: 0,
+                    arg_a_index: arg_a,
+                    arg_b_index: arg_b,
+                    top: 0,
+                }
+            },
+        };
+
+        let interval = operation_interval(&operation, &intervals, domains);
+
+        // Variables all share a key, so skip looking for repeats of them
+        if operation.op_type == OpType::Var || (!merge && operation.op_type != OpType::Const) {
+            new_index[i] = optimized.len();
+            optimized.push(operation);
+            intervals.push(interval);
+            continue;
+        }
+
+        let (index, merged) = push_operation(operation, interval, &mut optimized, &mut intervals, &mut existing);
+        new_index[i] = index;
+
+        if merged {
+            stats.merged += 1;
+        }
+    }
+
+    // Keep what the variables end up as, inputs, anything that might fail and everything those use
+    let mut keep = vec![false; optimized.len()];
+
+    for i in 0..vars.len() {
+        if vars[i].op_type == OpType::Var {
+            keep[new_index[i]] = true;
+            keep[new_index[vars[i].top]] = true;
+        }
+    }
+
+    for i in (0..optimized.len()).rev() {
+        let op_type = optimized[i].op_type;
+
+        if matches!(op_type, OpType::Var | OpType::Const | OpType::Inp) {
+            keep[i] = keep[i] || op_type == OpType::Inp;
+            continue;
+        }
+
+        let (arg_a, arg_b) = (optimized[i].arg_a_index, optimized[i].arg_b_index);
+        keep[i] = keep[i] || may_fail(op_type, intervals[arg_a], intervals[arg_b]);
+
+        if keep[i] {
+            keep[arg_a] = true;
+            keep[arg_b] = true;
+        }
+    }
+
+    // Pack the kept nodes together, which keeps arguments before the operations using them
+    let mut packed_index = vec![0; optimized.len()];
+    let mut packed = Vec::<Operation>::new();
+
+    for (i, mut operation) in optimized.into_iter().enumerate() {
+        if !keep[i] {
+            stats.dead += 1;
+            continue;
+        }
+
+        if !matches!(operation.op_type, OpType::Var | OpType::Const | OpType::Inp) {
+            operation.arg_a_index = packed_index[operation.arg_a_index];
+            operation.arg_b_index = packed_index[operation.arg_b_index];
+        }
+
+        packed_index[i] = packed.len();
+        packed.push(operation);
+    }
+
+    for i in 0..vars.len() {
+        if vars[i].op_type == OpType::Var {
+            packed[packed_index[new_index[i]]].top = packed_index[new_index[vars[i].top]];
+        }
+    }
+
+    stats.nodes_after = packed.iter().filter(|var| var.op_type != OpType::Var).count();
+    return (packed, stats);
+}
+
+// Build the operations for a program and optimize them with every input a digit from 1 to 9
+pub fn build_optimized_operations(input: &str) -> AocResult<Vec<Operation>> {
+    let vars = build_operations(input)?;
+    return Ok(optimize_operations(&vars, &default_domains(&vars)).0);
+}
+
+// The registers in the order of the variables they hold
+const REGISTERS: [Register; 4] = [Register::W, Register::X, Register::Y, Register::Z];
+
+// Set a register to a value or a copy of another register, skipping clearing it if it is known to be 0
+fn set_register(instructions: &mut Vec<Instruction>, is_zero: &mut [bool; 4], register: usize, operand: Operand) {
+    if !is_zero[register] {
+        instructions.push(Instruction::Mul(REGISTERS[register], Operand::Value(0)));
+    }
+
+    if operand != Operand::Value(0) {
+        instructions.push(Instruction::Add(REGISTERS[register], operand));
+    }
+
+    is_zero[register] = operand == Operand::Value(0);
+}
+
+// Swap every register an instruction uses for the one given in its place
+fn rename_registers(instruction: Instruction, renamed: &[usize; 4]) -> Instruction {
+    let rename = |register: Register| REGISTERS[renamed[register as usize]];
+    let rename_operand = |operand: Operand| {
+        match operand {
+            Operand::Register(register) => return Operand::Register(rename(register)),
+            Operand::Value(val) => return Operand::Value(val),
+        }
+    };
+
+    match instruction {
+        Instruction::Inp(register) => return Instruction::Inp(rename(register)),
+        Instruction::Add(register, operand) => return Instruction::Add(rename(register), rename_operand(operand)),
+        Instruction::Mul(register, operand) => return Instruction::Mul(rename(register), rename_operand(operand)),
+        Instruction::Div(register, operand) => return Instruction::Div(rename(register), rename_operand(operand)),
+        Instruction::Mod(register, operand) => return Instruction::Mod(rename(register), rename_operand(operand)),
+        Instruction::Eql(register, operand) => return Instruction::Eql(rename(register), rename_operand(operand)),
+    }
+}
+
+// Write operations back out as ALU instructions leaving each variable with the same value
+// Values are kept in the four variables until their last use, giving None if more than four are needed at once
+pub fn operations_to_instructions(vars: &[Operation]) -> Option<Vec<Instruction>> {
+    let is_value = |index: usize| !matches!(vars[index].op_type, OpType::Var | OpType::Const | OpType::Inp);
+
+    // The last operation using each node, with what the variables end up as used at the very end
+    let mut last_use: Vec<Option<usize>> = vec![None; vars.len()];
+
+    for i in 0..vars.len() {
+        if is_value(i) {
+            last_use[vars[i].arg_a_index] = Some(i);
+            last_use[vars[i].arg_b_index] = Some(i);
+        }
+    }
+
+    let register_vars = (0..vars.len()).filter(|i| vars[*i].op_type == OpType::Var).collect::<Vec<usize>>();
+
+    if register_vars.len() != REGISTERS.len() {
+        return None;
+    }
+
+    for var in &register_vars {
+        last_use[vars[*var].top] = Some(usize::MAX);
+    }
+
+    let mut instructions = Vec::<Instruction>::new();
+    let mut holding: [Option<usize>; 4] = [None; 4];
+    let mut location: Vec<Option<usize>> = vec![None; vars.len()];
+    let mut is_zero = [true; 4];
+
+    for i in 0..vars.len() {
+        match vars[i].op_type {
+            // Constants are only put in a variable where an instruction cannot take them as they are
+            OpType::Var | OpType::Const => continue,
+            OpType::Inp => {
+                let register = holding.iter().position(|node| node.is_none())?;
+                instructions.push(Instruction::Inp(REGISTERS[register]));
+                is_zero[register] = false;
+
+                if last_use[i].is_some() {
+                    holding[register] = Some(i);
+                    location[i] = Some(register);
+                }
+            },
+            op_type => {
+                let (mut arg_a, mut arg_b) = (vars[i].arg_a_index, vars[i].arg_b_index);
+                let dies = |node: usize| last_use[node] == Some(i);
+
+                // For operations where order does not matter, prefer a constant second and overwriting a value no longer needed
+                if matches!(op_type, OpType::Add | OpType::Mul | OpType::Eql) && location[arg_b].is_some()
+                    && (location[arg_a].is_none() || (!dies(arg_a) && dies(arg_b))) {
+                    (arg_a, arg_b) = (arg_b, arg_a);
+                }
+
+                let register = match location[arg_a] {
+                    Some(register) if dies(arg_a) => register,
+                    Some(from_register) => {
+                        let register = holding.iter().position(|node| node.is_none())?;
+                        set_register(&mut instructions, &mut is_zero, register, Operand::Register(REGISTERS[from_register]));
+                        register
+                    },
+                    None => {
+                        let register = holding.iter().position(|node| node.is_none())?;
+                        set_register(&mut instructions, &mut is_zero, register, Operand::Value(vars[arg_a].val));
+                        register
+                    },
+                };
+
+                let operand = match location[arg_b] {
+                    Some(from_register) => Operand::Register(REGISTERS[from_register]),
+                    None => Operand::Value(vars[arg_b].val),
+                };
+
+                instructions.push(match op_type {
+                    OpType::Add => Instruction::Add(REGISTERS[register], operand),
+                    OpType::Mul => Instruction::Mul(REGISTERS[register], operand),
+                    OpType::Div => Instruction::Div(REGISTERS[register], operand),
+                    OpType::Mod => Instruction::Mod(REGISTERS[register], operand),
+                    _ => Instruction::Eql(REGISTERS[register], operand),
+                });
+
+                is_zero[register] = false;
+
+                if let Some(from_register) = location[arg_b] {
+                    if dies(arg_b) && from_register != register {
+                        holding[from_register] = None;
+                    }
+                }
+
+                if location[arg_a] == Some(register) {
+                    location[arg_a] = None;
+                }
+
+                location[arg_b] = location[arg_b].filter(|_| !dies(arg_b));
+                holding[register] = None;
+
+                // An operation kept only because it might fail still has to run, but its result can go straight away
+                if last_use[i].is_some() {
+                    holding[register] = Some(i);
+                    location[i] = Some(register);
+                }
+            },
+        }
+    }
+
+    // Rename the registers so each value already in one ends up in the variable it belongs to
+    // Registers holding nothing wanted take the variables left over
+    let final_nodes = register_vars.iter().map(|var| vars[*var].top).collect::<Vec<usize>>();
+    let mut renamed: [Option<usize>; 4] = [None; 4];
+
+    for register in 0..REGISTERS.len() {
+        let node = final_nodes[register];
+
+        if let Some(from_register) = location[node] {
+            if final_nodes.iter().position(|final_node| *final_node == node) == Some(register) {
+                renamed[from_register] = Some(register);
+            }
+        }
+    }
+
+    let mut left_over = (0..REGISTERS.len()).filter(|register| !renamed.contains(&Some(*register))).collect::<Vec<usize>>().into_iter();
+    let renamed = renamed.map(|register| register.or_else(|| left_over.next()).unwrap());
+
+    let mut instructions = instructions.into_iter().map(|instruction| rename_registers(instruction, &renamed)).collect::<Vec<Instruction>>();
+    let mut renamed_zero = [false; 4];
+
+    for register in 0..REGISTERS.len() {
+        renamed_zero[renamed[register]] = is_zero[register];
+    }
+
+    // Then fill in the variables ending up with a constant or with the same value as another
+    for register in 0..REGISTERS.len() {
+        let node = final_nodes[register];
+
+        match location[node].map(|from_register| renamed[from_register]) {
+            Some(from_register) if from_register == register => (),
+            Some(from_register) => set_register(&mut instructions, &mut renamed_zero, register, Operand::Register(REGISTERS[from_register])),
+            None => set_register(&mut instructions, &mut renamed_zero, register, Operand::Value(vars[node].val)),
+        }
+    }
+
+    return Some(instructions);
+}
+
+// Write operations back out as ALU program text, giving None if more than four values are needed at once
+pub fn operations_to_program(vars: &[Operation]) -> Option<String> {
+    let instructions = operations_to_instructions(vars)?;
+    return Some(instructions.iter().map(|instruction| format!("{}\n", instruction)).collect());
+}
+
+// Optimize a program's operations and write them back out as a program
+// Merging repeated operations can keep more values around than the four variables hold, in which case the operations
+// are optimized again without merging, and failing that the original operations are written out as they are
+pub fn optimized_program(vars: &[Operation], domains: &[Interval]) -> AocResult<(String, OptimizeStats)> {
+    for merge in [true, false] {
+        let (optimized, stats) = optimize_operations_with(vars, domains, merge);
+
+        if let Some(program) = operations_to_program(&optimized) {
+            return Ok((program, stats));
+        }
+    }
+
+    let num_nodes = vars.iter().filter(|var| var.op_type != OpType::Var).count();
+
+    match operations_to_program(vars) {
+        Some(program) => return Ok((program, OptimizeStats {nodes_before: num_nodes, nodes_after: num_nodes, ..OptimizeStats::default()})),
+        None => return Err(AocError::NoSolution("the operations need more than 4 variables at once".to_string())),
+    }
+}
+
+// Work out a node's value for the given digits, giving None if an operation it depends on would make the ALU fail
+// or there is no digit for one of its inputs
+pub fn alu_solve(index: usize, digits: &[i64], vars: &[Operation]) -> Option<i64> {
+    match vars[index].op_type {
+        OpType::Var | OpType::Const => return Some(vars[index].val),
+        OpType::Inp => return digits.get(vars[index].input_digit).copied(),
+        op_type => return apply_op(op_type, alu_solve(vars[index].arg_a_index, digits, vars)?, alu_solve(vars[index].arg_b_index, digits, vars)?),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+// The second argument of an instruction
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Operand {
+    Register(Register),
+    Value(i64),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Register::W => return write!(f, "w"),
+            Register::X => return write!(f, "x"),
+            Register::Y => return write!(f, "y"),
+            Register::Z => return write!(f, "z"),
+        }
+    }
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Register(register) => return write!(f, "{}", register),
+            Operand::Value(val) => return write!(f, "{}", val),
+        }
+    }
+}
+
+// Written the way the program has it, such as add x -3
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Instruction::Inp(register) => return write!(f, "inp {}", register),
+            Instruction::Add(register, operand) => return write!(f, "add {} {}", register, operand),
+            Instruction::Mul(register, operand) => return write!(f, "mul {} {}", register, operand),
+            Instruction::Div(register, operand) => return write!(f, "div {} {}", register, operand),
+            Instruction::Mod(register, operand) => return write!(f, "mod {} {}", register, operand),
+            Instruction::Eql(register, operand) => return write!(f, "eql {} {}", register, operand),
+        }
+    }
+}
+
+fn parse_register(token: &str, line: &str, line_num: usize) -> AocResult<Register> {
+    match token {
+        "w" => return Ok(Register::W),
+        "x" => return Ok(Register::X),
+        "y" => return Ok(Register::Y),
+        "z" => return Ok(Register::Z),
+        _ => return Err(AocError::parse(line_num, error::column_of(line, token), format!("bad variable \"{}\"", token))),
+    }
+}
+
+fn parse_operand(token: &str, line: &str, line_num: usize) -> AocResult<Operand> {
+    if let Ok(register) = parse_register(token, line, line_num) {
+        return Ok(Operand::Register(register));
+    }
+
+    match token.parse::<i64>() {
+        Ok(val) => return Ok(Operand::Value(val)),
+        Err(_) => return Err(AocError::parse(line_num, error::column_of(line, token), format!("bad variable or number \"{}\"", token))),
+    }
+}
+
+pub fn parse_instruction(line: &str, line_num: usize) -> AocResult<Instruction> {
+    let args = line.split_whitespace().collect::<Vec<&str>>();
+
+    if args.is_empty() {
+        return Err(AocError::parse(line_num, 1, "empty instruction"));
+    }
+
+    let num_args = if args[0] == "inp" {2} else {3};
+
+    if args.len() != num_args {
+        return Err(AocError::parse(line_num, 1, format!("expected {} arguments for {}, found {}", num_args - 1, args[0], args.len() - 1)));
+    }
+
+    let register = parse_register(args[1], line, line_num)?;
+
+    if args[0] == "inp" {
+        return Ok(Instruction::Inp(register));
+    }
+
+    let operand = parse_operand(args[2], line, line_num)?;
+
+    match args[0] {
+        "add" => return Ok(Instruction::Add(register, operand)),
+        "mul" => return Ok(Instruction::Mul(register, operand)),
+        "div" => return Ok(Instruction::Div(register, operand)),
+        "mod" => return Ok(Instruction::Mod(register, operand)),
+        "eql" => return Ok(Instruction::Eql(register, operand)),
+        _ => return Err(AocError::parse(line_num, 1, format!("invalid command \"{}\"", args[0]))),
+    }
+}
+
+pub fn parse_program(input: &str) -> AocResult<Vec<Instruction>> {
+    let mut program = Vec::<Instruction>::new();
+
+    for (line_num, line) in error::numbered_lines(input) {
+        program.push(parse_instruction(line, line_num)?);
+    }
+
+    return Ok(program);
+}
+
+// A plain register machine running ALU programs one instruction at a time, taking inputs from a queue
+#[derive(Clone, Debug, Default)]
+pub struct Alu {
+    registers: [i64; 4],
+    inputs: VecDeque<i64>,
+}
+
+impl Alu {
+    pub fn new(inputs: impl IntoIterator<Item = i64>) -> Alu {
+        return Alu {
+            registers: [0; 4],
+            inputs: inputs.into_iter().collect(),
+        };
+    }
+
+    pub fn register(&self, register: Register) -> i64 {
+        return self.registers[register as usize];
+    }
+
+    pub fn push_input(&mut self, input: i64) {
+        self.inputs.push_back(input);
+    }
+
+    fn operand(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => return self.register(register),
+            Operand::Value(val) => return val,
+        }
+    }
+
+    // Instructions the ALU cannot carry out give NoSolution, as the program is fine but these inputs make it fail
+    pub fn execute(&mut self, instruction: Instruction) -> AocResult<()> {
+        let overflow = || AocError::NoSolution("value does not fit in 64 bits".to_string());
+
+        let (register, result) = match instruction {
+            Instruction::Inp(register) => match self.inputs.pop_front() {
+                Some(input) => (register, input),
+                None => return Err(AocError::Input("inp with no input left".to_string())),
+            },
+            Instruction::Add(register, operand) => (register, self.register(register).checked_add(self.operand(operand)).ok_or_else(overflow)?),
+            Instruction::Mul(register, operand) => (register, self.register(register).checked_mul(self.operand(operand)).ok_or_else(overflow)?),
+            Instruction::Div(register, operand) => {
+                if self.operand(operand) == 0 {
+                    return Err(AocError::NoSolution("division by zero".to_string()));
+                }
+
+                (register, self.register(register).checked_div(self.operand(operand)).ok_or_else(overflow)?)
+            },
+            Instruction::Mod(register, operand) => {
+                // The ALU only defines mod for a >= 0 and b > 0
+                if self.register(register) < 0 || self.operand(operand) <= 0 {
+                    return Err(AocError::NoSolution(format!("mod of {} by {}", self.register(register), self.operand(operand))));
+                }
+
+                (register, self.register(register) % self.operand(operand))
+            },
+            Instruction::Eql(register, operand) => (register, if self.register(register) == self.operand(operand) {1} else {0}),
+        };
+
+        self.registers[register as usize] = result;
+        return Ok(());
+    }
+
+    // Run every instruction in order, giving the line of the instruction that failed if any do
+    // The program itself was already parsed, so failures are not reported as parse errors
+    pub fn run(&mut self, program: &[Instruction]) -> AocResult<()> {
+        for (instruction_index, instruction) in program.iter().enumerate() {
+            self.execute(*instruction).map_err(|err| {
+                match err {
+                    AocError::Input(message) => return AocError::Input(format!("line {}: {}", instruction_index + 1, message)),
+                    AocError::NoSolution(message) => return AocError::NoSolution(format!("line {}: {}", instruction_index + 1, message)),
+                    err => return err,
+                }
+            })?;
+        }
+
+        return Ok(());
+    }
+}
+
+// Read a model number's digits, each of which must be 1 to 9
+// Inputs outside of that are written as whole numbers split by commas instead
+pub fn parse_model_number(model_number: &str) -> AocResult<Vec<i64>> {
+    const RADIX: u32 = 10;
+    let mut digits = Vec::<i64>::new();
+
+    if model_number.contains(',') {
+        for input in model_number.split(',') {
+            match input.trim().parse::<i64>() {
+                Ok(input_val) => digits.push(input_val),
+                Err(_) => return Err(AocError::parse(1, error::column_of(model_number, input.trim()), format!("bad input value \"{}\"", input.trim()))),
+            }
+        }
+
+        return Ok(digits);
+    }
+
+    for (char_index, digit_char) in model_number.chars().enumerate() {
+        match digit_char.to_digit(RADIX) {
+            Some(digit) if digit != 0 => digits.push(digit as i64),
+            _ => return Err(AocError::parse(1, char_index + 1, format!("bad model number digit '{}'", digit_char))),
+        }
+    }
+
+    return Ok(digits);
+}
+
+// Run the program on a model number's digits, which is valid if z ends up as 0
+pub fn is_valid_model_number(program: &[Instruction], digits: &[i64]) -> AocResult<bool> {
+    let mut alu = Alu::new(digits.iter().copied());
+    alu.run(program)?;
+
+    return Ok(alu.register(Register::Z) == 0);
+}
+
+// Build a tree of operations from the MONAD program, the index of z's calculation is vars[3].top
+pub fn build_operations(input: &str) -> AocResult<Vec<Operation>> {
+    let mut vars = Vec::<Operation>::new();
+
+    // Push number vars onto list
+    for name in ['w', 'x', 'y', 'z'] {
+        vars.push(Operation {
+            op_type: OpType::Var,
+            name,
+            val: 0,
+            input_digit: 0,
+            arg_a_index: 0,
+            arg_b_index: 0,
+            top: 4,
+        });
+    }
+
+    // Push initial state of 0 for all vars
+    vars.push(Operation {
+        op_type: OpType::Const,
+        name: '-',
+        val: 0,
+        input_digit: 0,
+        arg_a_index: 0,
+        arg_b_index: 0,
+        top: 0,
+    });
+
+    let mut current_digit = 0;
+
+    // Build a tree of operations for testing digits, with as many inputs as the program reads
+    for (line_num, line) in error::numbered_lines(input) {
+        current_digit = alu_inst(line, current_digit, &mut vars).map_err(|err| err.on_line(line_num))?;
+    }
+
+    return Ok(vars);
+}
+
+// Extract the digits of the zero solution, using the preferred value from the domain for any that make no difference
+pub fn find_model_number(solutions: &[Solution], domains: &[Interval], preference: Preference) -> AocResult<Vec<i64>> {
+    let zero_solution = match solutions.iter().find(|solution| solution.result == 0) {
+        Some(zero_solution) => zero_solution,
+        None => return Err(AocError::NoSolution("no model number gives z = 0".to_string())),
+    };
+
+    // Extract digits
+    let mut digits = domains.iter().map(|domain| preference.unused_digit(*domain)).collect::<Vec<i64>>();
+
+    for i in 0..digits.len() {
+        if zero_solution.digits[i].used {
+            digits[i] = zero_solution.digits[i].value;
+        }
+    }
+
+    return Ok(digits);
+}
+
+// Write the digits out as a model number, splitting them with commas if any are not a single digit
+pub fn model_number_string(digits: &[i64]) -> String {
+    if digits.iter().all(|digit| (0..=9).contains(digit)) {
+        return digits.iter().map(|digit| digit.to_string()).collect();
+    }
+
+    return digits.iter().map(|digit| digit.to_string()).collect::<Vec<String>>().join(",");
+}
+
+// Find the preferred model number with inputs from the given domains, checking it by running the program itself
+pub fn model_number_in(vars: &[Operation], program: &[Instruction], domains: &[Interval], preference: Preference) -> AocResult<Vec<i64>> {
+    let solutions = alu_any_solve(vars[3].top, vars, domains, 0, preference)?;
+    let digits = find_model_number(&solutions, domains, preference)?;
+
+    if !is_valid_model_number(program, &digits)? {
+        return Err(AocError::NoSolution(format!("model number {} does not give z = 0", model_number_string(&digits))));
+    }
+
+    return Ok(digits);
+}
+
+// Find the preferred model number the program accepts, with every input a digit from 1 to 9
+pub fn solve_model_number(input: &str, preference: Preference) -> AocResult<String> {
+    let vars = build_optimized_operations(input)?;
+    let digits = model_number_in(&vars, &parse_program(input)?, &default_domains(&vars), preference)?;
+
+    return Ok(model_number_string(&digits));
+}
+
+pub fn part1(input: &str) -> AocResult<String> {
+    return solve_model_number(input, Preference::Largest);
+}
+
+pub fn part2(input: &str) -> AocResult<String> {
+    return solve_model_number(input, Preference::Smallest);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Adds every digit into z
+    fn digit_sum_program() -> String {
+        return "inp w\nadd z w\n".repeat(14);
+    }
+
+    #[test]
+    fn solves_program() {
+        let vars = build_operations(&digit_sum_program()).unwrap();
+        assert_eq!(Some(14), alu_solve(vars[3].top, &[1; 14], &vars));
+        assert_eq!(Some(126), alu_solve(vars[3].top, &[9; 14], &vars));
+        assert_eq!(None, alu_solve(vars[3].top, &[1; 13], &vars));
+
+        // Failing operations give no value rather than panicking, and mod only takes a non-negative by a positive
+        for (program, register, digit) in [("inp x\ndiv z x\n", 3, 0), ("inp x\nmod x 3\n", 1, -4), ("inp x\nmul x x\nmul x x\n", 1, 1 << 20)] {
+            let vars = build_operations(program).unwrap();
+            assert_eq!(None, alu_solve(vars[register].top, &[digit], &vars));
+        }
+    }
+
+    #[test]
+    fn finds_model_numbers() {
+        // Only model numbers with digits adding up to 20 are valid
+        let program = digit_sum_program() + "eql z 20\neql z 0\n";
+        let vars = build_operations(&program).unwrap();
+
+        assert_eq!("71111111111111", solve_model_number(&program, Preference::Largest).unwrap());
+        assert_eq!("11111111111117", solve_model_number(&program, Preference::Smallest).unwrap());
+
+        let solutions = alu_any_solve(vars[3].top, &vars, &default_domains(&vars), 0, Preference::Largest).unwrap();
+        assert!(solutions.iter().all(|solution| solution.result == 0));
+
+        // Digits adding up to more than 126 are impossible
+        let program = digit_sum_program() + "eql z 127\neql z 0\n";
+        assert!(matches!(part1(&program), Err(AocError::NoSolution(_))));
+    }
+
+    #[test]
+    fn combines_and_trims_solutions() {
+        let digit = |value| SolutionDigit {used: true, value};
+        let (mut low, mut high) = (create_empty_solution(5, 2), create_empty_solution(5, 2));
+        low.digits[0] = digit(1);
+        high.digits[0] = digit(2);
+
+        let mut other = create_empty_solution(0, 2);
+        other.digits[1] = digit(3);
+        let (combined, success) = combine_solutions(7, &high, &other);
+        assert!(success);
+        assert_eq!((7, 2, 3), (combined.result, combined.digits[0].value, combined.digits[1].value));
+        assert!(!combine_solutions(7, &low, &high).1);
+
+        let first_digits = |solutions: &[Solution]| solutions.iter().map(|solution| (solution.result, solution.digits[0].value)).collect::<Vec<(i64, i64)>>();
+        let mut solutions = vec![low.clone(), combined.clone(), high.clone()];
+        trim_solutions(&mut solutions, Preference::Largest);
+        assert_eq!(vec![(5, 2), (7, 2)], first_digits(&solutions));
+
+        let mut solutions = vec![combined, high, low];
+        trim_solutions(&mut solutions, Preference::Smallest);
+        assert_eq!(vec![(5, 1), (7, 2)], first_digits(&solutions));
+    }
+
+    #[test]
+    fn solves_any_number_of_inputs() {
+        // Valid when the three inputs add up to 25
+        let input = "inp x\ninp y\nadd x y\ninp z\nadd z x\nadd z -25\n";
+        let vars = build_operations(input).unwrap();
+        let program = parse_program(input).unwrap();
+        assert_eq!(3, count_inputs(&vars));
+
+        assert_eq!("997", solve_model_number(input, Preference::Largest).unwrap());
+        assert_eq!("799", solve_model_number(input, Preference::Smallest).unwrap());
+
+        let domains = parse_domains("0..20", 3).unwrap();
+        assert_eq!(vec![20, 5, 0], model_number_in(&vars, &program, &domains, Preference::Largest).unwrap());
+        assert_eq!("0,5,20", model_number_string(&model_number_in(&vars, &program, &domains, Preference::Smallest).unwrap()));
+
+        let domains = parse_domains("1..1, -5..30, 2..3", 3).unwrap();
+        assert_eq!(vec![1, 22, 2], model_number_in(&vars, &program, &domains, Preference::Largest).unwrap());
+        assert_eq!(vec![1, 21, 3], model_number_in(&vars, &program, &domains, Preference::Smallest).unwrap());
+        assert!(is_valid_model_number(&program, &parse_model_number("1, 21, 3").unwrap()).unwrap());
+
+        // Each input needs a domain of its own
+        assert_eq!(AocError::Input("expected 3 domains, given 2".to_string()),
+                   alu_any_solve(vars[3].top, &vars, &parse_domains("1..9", 2).unwrap(), 0, Preference::Largest).err().unwrap());
+    }
+
+    #[test]
+    fn solves_domains_where_runs_fail() {
+        // Needs x mod y to be 2, which fails for negative x and for y of 0 or less
+        let input = "inp x\ninp y\nmod x y\nadd z x\nadd z -2\n";
+        let vars = build_operations(input).unwrap();
+        let program = parse_program(input).unwrap();
+        let domains = parse_domains("-3..5", 2).unwrap();
+        let (vars, _) = optimize_operations(&vars, &domains);
+
+        assert_eq!(vec![5, 3], model_number_in(&vars, &program, &domains, Preference::Largest).unwrap());
+        assert_eq!(vec![2, 3], model_number_in(&vars, &program, &domains, Preference::Smallest).unwrap());
+    }
+
+    #[test]
+    fn rejects_bad_domains() {
+        assert_eq!(AocError::parse(1, 6, "expected MIN..MAX, found \"3\""), parse_domains("1..9,3", 2).err().unwrap());
+        assert_eq!(AocError::parse(1, 1, "bad domain \"a..9\""), parse_domains("a..9", 2).err().unwrap());
+        assert_eq!(AocError::parse(1, 1, "domain 9..1 is empty"), parse_domains("9..1", 2).err().unwrap());
+        assert_eq!(AocError::Input("expected 1 or 3 domains, found 2".to_string()), parse_domains("1..9,1..9", 3).err().unwrap());
+        assert_eq!(AocError::parse(1, 6, "domain 0..1000 has more than 1000 values"), parse_domains("1..9,0..1000", 2).err().unwrap());
+        assert_eq!(1000, parse_domains("-500..499", 1).unwrap()[0].size());
+
+        let input = "inp x\ninp y\n";
+        let vars = build_operations(input).unwrap();
+        let domains = [DEFAULT_DOMAIN, Interval {min: i64::MIN, max: i64::MAX}];
+        assert!(matches!(model_number_in(&vars, &parse_program(input).unwrap(), &domains, Preference::Largest), Err(AocError::Input(_))));
+    }
+
+    #[test]
+    fn avoids_failing_runs() {
+        // z is always 0, but the last div fails unless the second digit is at least 3
+        let input = "inp y\nadd y w\ndiv w 9\nadd w 3\nadd x x\ninp y\nmod x 3\nmod x 2\ndiv y w\ndiv w y\n";
+
+        assert_eq!("13", solve_model_number(input, Preference::Smallest).unwrap());
+        assert_eq!("99", solve_model_number(input, Preference::Largest).unwrap());
+
+        // Every run fails, so there is nothing to find
+        assert!(matches!(solve_model_number("inp x\nadd x -10\nmod x 3\n", Preference::Largest), Err(AocError::NoSolution(_))));
+    }
+
+    #[test]
+    fn bounds_operations() {
+        let digit = Interval {min: 1, max: 9};
+
+        assert_eq!(Interval {min: -8, max: 8}, apply_op_interval(OpType::Add, digit, Interval {min: -9, max: -1}));
+        assert_eq!(Interval {min: -81, max: 81}, apply_op_interval(OpType::Mul, digit, Interval {min: -9, max: 9}));
+        assert_eq!(Interval {min: -9, max: 9}, apply_op_interval(OpType::Div, digit, Interval {min: -1, max: 1}));
+        assert_eq!(digit, apply_op_interval(OpType::Mod, digit, Interval::exactly(26)));
+        assert_eq!(Interval {min: 0, max: 3}, apply_op_interval(OpType::Mod, Interval {min: 0, max: 100}, Interval::exactly(4)));
+        assert_eq!(Interval::exactly(0), apply_op_interval(OpType::Eql, digit, Interval::exactly(10)));
+        assert_eq!(Interval {min: 0, max: 1}, apply_op_interval(OpType::Eql, digit, Interval::exactly(9)));
+        assert_eq!(Interval {min: i64::MIN, max: i64::MAX}, apply_op_interval(OpType::Mul, Interval {min: i64::MIN, max: i64::MAX}, Interval::exactly(2)));
+    }
+
+    // Run a program and the program written from its optimized operations on every input from 1 to 9, checking both end the same
+    fn check_optimized_program(input: &str, num_inputs: u32) {
+        let program = parse_program(input).unwrap();
+        let vars = build_operations(input).unwrap();
+        let optimized_program = parse_program(&optimized_program(&vars, &default_domains(&vars)).unwrap().0).unwrap();
+
+        for input_set in 0..9_i64.pow(num_inputs) {
+            let digits = (0..num_inputs).map(|digit| input_set / 9_i64.pow(digit) % 9 + 1).collect::<Vec<i64>>();
+            let (mut alu, mut optimized_alu) = (Alu::new(digits.clone()), Alu::new(digits.clone()));
+            alu.run(&program).unwrap();
+            optimized_alu.run(&optimized_program).unwrap();
+
+            for register in [Register::W, Register::X, Register::Y, Register::Z] {
+                assert_eq!(alu.register(register), optimized_alu.register(register), "{:?} with {:?}", register, digits);
+            }
+        }
+    }
+
+    #[test]
+    fn optimizes_operations() {
+        // y ends up the same as x, w is always 8 and the first value of y is never used
+        let input = "inp x\ninp y\nadd x y\nadd y x\nmul y 0\nadd y x\ndiv y 1\nadd y 0\ninp z\neql w 10\nadd w 4\nmul w 2\n\
+            inp w\nmul w 0\nadd w 8\nmul z x\nmul z y\neql z 10\neql z 0\nmul z x\n";
+        let vars = build_operations(input).unwrap();
+        let (optimized, stats) = optimize_operations(&vars, &default_domains(&vars));
+
+        assert_eq!(OptimizeStats {nodes_before: 31, nodes_after: 13, folded: 6, simplified: 3, pruned: 0, merged: 6, dead: 4}, stats);
+        assert_eq!(8, optimized[optimized[0].top].val);
+        assert_eq!(optimized[1].top, optimized[2].top);
+        assert_eq!(4, count_inputs(&optimized));
+
+        check_optimized_program(input, 4);
+        check_optimized_program("inp z\ninp w\n", 2);
+        check_optimized_program("inp w\ninp x\ninp y\ninp z\nadd w z\nadd x w\nmul y x\nmod z 3\n", 4);
+    }
+
+    #[test]
+    fn writes_programs_under_register_pressure() {
+        // Sharing the first input mod the second between y and z keeps it needed alongside all three inputs
+        let input = "inp y\nadd x 11\ninp x\nadd z y\ninp w\nmod y x\nmod y 3\nmul w z\nmod z x\nmod z w\n";
+        let vars = build_operations(input).unwrap();
+
+        assert_eq!(None, operations_to_program(&optimize_operations(&vars, &default_domains(&vars)).0));
+        assert_eq!(0, optimized_program(&vars, &default_domains(&vars)).unwrap().1.merged);
+        check_optimized_program(input, 3);
+    }
+
+    #[test]
+    fn swaps_variables_without_overflowing() {
+        // x and y trade places, which must not go through adding them together
+        let input = "inp x\ninp y\nadd z x\nmul x 0\nadd x y\nmul y 0\nadd y z\n";
+        let vars = build_operations(input).unwrap();
+        let program = parse_program(&optimized_program(&vars, &default_domains(&vars)).unwrap().0).unwrap();
+        let mut alu = Alu::new([i64::MAX, i64::MIN]);
+        alu.run(&program).unwrap();
+
+        assert_eq!([0, i64::MIN, i64::MAX, i64::MAX], [Register::W, Register::X, Register::Y, Register::Z].map(|register| alu.register(register)));
+        check_optimized_program(input, 2);
+    }
+
+    #[test]
+    fn prunes_by_range() {
+        let vars = build_operations("inp x\nmul x 3\nadd x -2\n").unwrap();
+        assert_eq!(Interval {min: 1, max: 25}, operation_intervals(&vars, &default_domains(&vars))[vars[1].top]);
+        assert_eq!(Interval {min: -5, max: 1}, operation_intervals(&vars, &parse_domains("-1..1", 1).unwrap())[vars[1].top]);
+
+        // Every digit is below 10 and every digit plus 10 is below 26
+        let input = "inp x\nmod x 10\ninp y\ndiv y 10\ninp z\nadd z 10\nmod z 26\neql w z\n";
+        let vars = build_operations(input).unwrap();
+        let (optimized, stats) = optimize_operations(&vars, &default_domains(&vars));
+
+        assert_eq!(4, stats.pruned);
+        assert_eq!(OpType::Inp, optimized[optimized[1].top].op_type);
+        assert_eq!(0, optimized[optimized[2].top].val);
+        assert_eq!(OpType::Add, optimized[optimized[3].top].op_type);
+        assert_eq!(0, optimized[optimized[0].top].val);
+        check_optimized_program(input, 3);
+
+        // With inputs up to 30 none of them can go
+        assert_eq!(0, optimize_operations(&vars, &parse_domains("0..30", 3).unwrap()).1.pruned);
+    }
+
+    #[test]
+    fn keeps_operations_that_might_fail() {
+        // Nothing uses the mod, but a negative input would make the program fail
+        let input = "inp x\nmod x 5\nmul x 0\n";
+        let vars = build_operations(input).unwrap();
+
+        assert_eq!(4, optimize_operations(&vars, &parse_domains("-9..9", 1).unwrap()).0.len() - 4);
+        assert_eq!(2, optimize_operations(&vars, &default_domains(&vars)).0.len() - 4);
+
+        let vars = build_operations("inp x\nmul y 0\nmod x y\n").unwrap();
+        let optimized = optimize_operations(&vars, &default_domains(&vars)).0;
+
+        // The mod always fails, so no run ever finishes to give z or x a value
+        assert!(alu_any_solve(optimized[3].top, &optimized, &default_domains(&optimized), 0, Preference::Largest).unwrap().is_empty());
+        assert!(alu_any_solve(optimized[1].top, &optimized, &default_domains(&optimized), 0, Preference::Largest).unwrap().is_empty());
+    }
+
+    #[test]
+    fn lists_operations_once() {
+        let vars = build_operations("inp w\nadd w 6\nadd z w\nmul z w\nadd x 4\n").unwrap();
+
+        assert_eq!("t7 = d0 + 6\nt8 = 0 + t7\nt9 = t8 * t7\nz = t9\n", alu_listing('z', vars[3].top, &vars));
+
+        let optimized = optimize_operations(&vars, &default_domains(&vars)).0;
+        assert_eq!("x = 4\n", alu_listing('x', optimized[1].top, &optimized));
+    }
+
+    #[test]
+    fn folds_constants() {
+        let program = digit_sum_program() + "mul x 0\nadd x 3\nmul x 4\nadd z x\n";
+        let vars = optimize_operations(&build_operations(&program).unwrap(), &[DEFAULT_DOMAIN; 14]).0;
+        let x_top = vars[1].top;
+
+        assert!(vars[x_top].op_type == OpType::Const);
+        assert_eq!(12, vars[x_top].val);
+        assert_eq!(Some(26), alu_solve(vars[3].top, &[1; 14], &vars));
+    }
+
+    #[test]
+    fn runs_programs() {
+        // Binary digits of the input, from the puzzle's example
+        let program = parse_program("inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2\n").unwrap();
+        let mut alu = Alu::new([13]);
+        alu.run(&program).unwrap();
+
+        assert_eq!([1, 1, 0, 1], [Register::W, Register::X, Register::Y, Register::Z].map(|register| alu.register(register)));
+
+        let program = parse_program("inp z\ninp x\nmul z 3\neql z x\n").unwrap();
+        assert_eq!(1, {let mut alu = Alu::new([2, 6]); alu.run(&program).unwrap(); alu.register(Register::Z)});
+
+        assert!(is_valid_model_number(&parse_program(&digit_sum_program()).unwrap(), &[0; 14]).unwrap());
+        assert_eq!(vec![1, 3, 9], parse_model_number("139").unwrap());
+        assert_eq!(AocError::parse(1, 2, "bad model number digit '0'"), parse_model_number("109").err().unwrap());
+        assert!(!is_valid_model_number(&parse_program(&digit_sum_program()).unwrap(), &[1; 14]).unwrap());
+    }
+
+    #[test]
+    fn reports_alu_errors() {
+        assert_eq!(AocError::parse(1, 5, "bad variable \"q\""), parse_instruction("inp q", 1).err().unwrap());
+        assert_eq!(AocError::parse(2, 7, "bad variable or number \"1.5\""), parse_instruction("add x 1.5", 2).err().unwrap());
+
+        let program = parse_program("inp x\ndiv x 0\n").unwrap();
+        assert_eq!(AocError::NoSolution("line 2: division by zero".to_string()), Alu::new([3]).run(&program).err().unwrap());
+        assert_eq!(AocError::Input("line 1: inp with no input left".to_string()), Alu::new([]).run(&program).err().unwrap());
+
+        let program = parse_program("add x -1\nmod x 2\n").unwrap();
+        assert_eq!(AocError::NoSolution("line 2: mod of -1 by 2".to_string()), Alu::new([]).run(&program).err().unwrap());
+    }
+
+    #[test]
+    fn rejects_bad_programs() {
+        let program = digit_sum_program() + "sub z 1\n";
+        assert_eq!(AocError::parse(29, 1, "invalid command \"sub\""), build_operations(&program).err().unwrap());
+
+        let program = digit_sum_program() + "div z 0\n";
+        assert_eq!(AocError::parse(29, 1, "division by zero"), build_operations(&program).err().unwrap());
+
+        assert_eq!(1, count_inputs(&build_operations("inp w\n").unwrap()));
+    }
+}
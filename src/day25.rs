@@ -0,0 +1,122 @@
+// Day 25: Sea Cucumber
+
+use std::fmt;
+
+use crate::error::AocResult;
+use crate::grid::Grid;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum CucumberState {
+    East,
+    South,
+    None,
+    Moving,
+}
+
+impl fmt::Display for CucumberState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CucumberState::East => write!(f, ">"),
+            CucumberState::South => write!(f, "v"),
+            CucumberState::None | CucumberState::Moving => write!(f, "."),
+        }
+    }
+}
+
+pub fn parse_cucumber_map(input: &str) -> AocResult<Grid<CucumberState>> {
+    return Grid::parse(input, "character", |slot| match slot {
+        '>' => Some(CucumberState::East),
+        'v' => Some(CucumberState::South),
+        '.' => Some(CucumberState::None),
+        _ => None,
+    });
+}
+
+// Move every cucumber facing the given direction that has a free space ahead of it, wrapping around the edges
+fn move_herd(cucumber_map: &mut Grid<CucumberState>, herd: CucumberState, dx: isize, dy: isize) -> bool {
+    let mut no_movement = true;
+
+    // Mark movers first so a cucumber never moves into a space freed up in the same step
+    for y in 0..cucumber_map.height() {
+        for x in 0..cucumber_map.width() {
+            if cucumber_map[(x, y)] == herd && cucumber_map[cucumber_map.wrapping_pos(x, y, dx, dy)] == CucumberState::None {
+                cucumber_map[(x, y)] = CucumberState::Moving;
+                no_movement = false;
+            }
+        }
+    }
+
+    for y in 0..cucumber_map.height() {
+        for x in 0..cucumber_map.width() {
+            if cucumber_map[(x, y)] == CucumberState::Moving {
+                cucumber_map[(x, y)] = CucumberState::None;
+                let target = cucumber_map.wrapping_pos(x, y, dx, dy);
+                cucumber_map[target] = herd;
+            }
+        }
+    }
+
+    return no_movement;
+}
+
+// Return true if there was no movement
+pub fn step(cucumber_map: &mut Grid<CucumberState>) -> bool {
+    let east_stopped = move_herd(cucumber_map, CucumberState::East, 1, 0);
+    let south_stopped = move_herd(cucumber_map, CucumberState::South, 0, 1);
+
+    return east_stopped && south_stopped;
+}
+
+// Step the map until no sea cucumber moves, returning the first step where nothing moved
+pub fn count_steps_until_stopped(cucumber_map: &mut Grid<CucumberState>) -> u32 {
+    let mut step_count: u32 = 1;
+
+    while !step(cucumber_map) {
+        step_count += 1;
+    }
+
+    return step_count;
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    let mut cucumber_map = parse_cucumber_map(input)?;
+    return Ok(count_steps_until_stopped(&mut cucumber_map));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day25.txt");
+
+    #[test]
+    fn steps_cucumbers() {
+        let mut cucumber_map = parse_cucumber_map("...>>>>>...\n").unwrap();
+
+        assert!(!step(&mut cucumber_map));
+        assert!(cucumber_map == parse_cucumber_map("...>>>>.>..\n").unwrap());
+
+        assert!(!step(&mut cucumber_map));
+        assert!(cucumber_map == parse_cucumber_map("...>>>.>.>.\n").unwrap());
+    }
+
+    #[test]
+    fn wraps_around_edges() {
+        let mut cucumber_map = parse_cucumber_map(".>\n.v\n").unwrap();
+
+        assert!(!step(&mut cucumber_map));
+        assert!(cucumber_map == parse_cucumber_map(">v\n..\n").unwrap());
+    }
+
+    #[test]
+    fn stops_moving() {
+        let mut cucumber_map = parse_cucumber_map(EXAMPLE).unwrap();
+        let mut step_count = 1;
+
+        while !step(&mut cucumber_map) {
+            step_count += 1;
+        }
+
+        assert_eq!(58, step_count);
+    }
+}
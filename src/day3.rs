@@ -0,0 +1,168 @@
+// Day 3: Binary Diagnostic
+
+use crate::error::{self, AocError, AocResult};
+
+pub struct Rating {
+    pub bits: String,
+    pub value: i32,
+}
+
+pub fn bin_str_to_i32(bin_str: &str) -> i32 {
+    let bin_iterator = bin_str.chars().rev();
+    let mut multiplier = 1;
+    let mut total = 0;
+
+    for bit in bin_iterator {
+        if bit == '1' {
+            total += multiplier;
+        }
+        multiplier *= 2;
+    }
+
+    return total;
+}
+
+fn create_rating(bits: &str) -> Rating {
+    return Rating {
+        bits: bits.to_string(),
+        value: bin_str_to_i32(bits),
+    };
+}
+
+// Get the diagnostic lines, which must all be binary numbers of the same length
+pub fn parse_diagnostic(input: &str) -> AocResult<Vec<&str>> {
+    let mut lines = Vec::<&str>::new();
+
+    for (line_num, line) in error::numbered_lines(input) {
+        if let Some(bad_index) = line.find(|c| c != '0' && c != '1') {
+            return Err(AocError::parse(line_num, bad_index + 1, "expected only 0 and 1"));
+        }
+
+        if line.is_empty() || line.len() > 31 {
+            return Err(AocError::parse(line_num, 1, "expected between 1 and 31 bits"));
+        }
+
+        if !lines.is_empty() && line.len() != lines[0].len() {
+            return Err(AocError::parse(line_num, 1, format!("expected {} bits like the first line", lines[0].len())));
+        }
+
+        lines.push(line);
+    }
+
+    if lines.is_empty() {
+        return Err(AocError::Input("no diagnostic lines".to_string()));
+    }
+
+    return Ok(lines);
+}
+
+// Get the gamma and epsilon rates from the most and least common bits
+pub fn get_gamma_epsilon(lines: &[&str]) -> (Rating, Rating) {
+    let num_bits = lines[0].len() as i32;
+    let num_lines = (lines.len() as i32) / 2;
+
+    let mut ones_counter = vec![0; num_bits as usize];
+
+    for line in lines {
+        for (bit_loc, bit) in line.chars().enumerate() {
+            if bit == '1' {
+                ones_counter[bit_loc] += 1;
+            }
+        }
+    }
+
+    let mut gamma_string: String = "".to_owned();
+    let mut epsilon_string: String = "".to_owned();
+
+    for bit in ones_counter as Vec<i32>{
+        if bit > num_lines {
+            gamma_string.push('1');
+            epsilon_string.push('0');
+        }
+        else {
+            gamma_string.push('0');
+            epsilon_string.push('1');
+        }
+    }
+
+    return (create_rating(&gamma_string), create_rating(&epsilon_string));
+}
+
+// Repeatedly filter lines by the most (or least) common bit until only one remains
+fn filter_by_bit_criteria(lines: &[&str], keep_most_common: bool) -> AocResult<Rating> {
+    let num_bits = lines[0].len() as i32;
+    let mut rating_vec = lines.to_vec();
+
+    for bit in 0..num_bits as usize {
+        let mut ones_counter = 0;
+
+        // Count 1s in the current bit row
+        for line in rating_vec.clone() {
+            if line.chars().nth(bit).unwrap() == '1' {
+                ones_counter += 1;
+            }
+        }
+
+        // Eliminate all lines with current bit not in the majority / minority, ties keep 1s for o2 and 0s for co2
+        let ones_are_common = 2 * ones_counter >= rating_vec.len();
+        let keep_bit = if keep_most_common == ones_are_common {'1'} else {'0'};
+
+        rating_vec.retain(|line| {
+            return line.chars().nth(bit).unwrap() == keep_bit;
+        });
+
+        if rating_vec.len() <= 1 {
+            break;
+        }
+    }
+
+    if rating_vec.is_empty() {
+        return Err(AocError::NoSolution("every line was filtered out of the rating".to_string()));
+    }
+
+    return Ok(create_rating(rating_vec[0]));
+}
+
+// Get the oxygen generator and CO2 scrubber ratings
+pub fn get_o2_co2(lines: &[&str]) -> AocResult<(Rating, Rating)> {
+    return Ok((filter_by_bit_criteria(lines, true)?, filter_by_bit_criteria(lines, false)?));
+}
+
+pub fn part1(input: &str) -> AocResult<i32> {
+    let lines = parse_diagnostic(input)?;
+    let (gamma, epsilon) = get_gamma_epsilon(&lines);
+    return Ok(gamma.value * epsilon.value);
+}
+
+pub fn part2(input: &str) -> AocResult<i32> {
+    let lines = parse_diagnostic(input)?;
+    let (o2_gen, co2_scrub) = get_o2_co2(&lines)?;
+    return Ok(o2_gen.value * co2_scrub.value);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day03.txt");
+
+    #[test]
+    fn gets_gamma_epsilon() {
+        let (gamma, epsilon) = get_gamma_epsilon(&parse_diagnostic(EXAMPLE).unwrap());
+        assert_eq!(("10110", 22), (gamma.bits.as_str(), gamma.value));
+        assert_eq!(("01001", 9), (epsilon.bits.as_str(), epsilon.value));
+    }
+
+    #[test]
+    fn gets_o2_co2() {
+        let (o2_gen, co2_scrub) = get_o2_co2(&parse_diagnostic(EXAMPLE).unwrap()).unwrap();
+        assert_eq!(("10111", 23), (o2_gen.bits.as_str(), o2_gen.value));
+        assert_eq!(("01010", 10), (co2_scrub.bits.as_str(), co2_scrub.value));
+    }
+
+    #[test]
+    fn rejects_uneven_lines() {
+        assert!(matches!(parse_diagnostic("101\n10\n"), Err(AocError::Parse { line: 2, .. })));
+        assert!(matches!(parse_diagnostic("101\n121\n"), Err(AocError::Parse { line: 2, column: 2, .. })));
+    }
+}
@@ -0,0 +1,270 @@
+// Day 4: Giant Squid
+
+use crate::error::{self, AocError, AocResult};
+
+#[derive(Copy, Clone)]
+pub struct BingoSlot {
+    pub val: u32,
+    pub marked: bool,
+}
+
+#[derive(Clone)]
+pub struct BingoCard {
+    pub card: [[BingoSlot; 5]; 5],
+    pub is_winner: bool,
+}
+
+// Create a card from 5 lines of 5 numbers, the first line being at the given line number
+pub fn create_bingo_card_from_5x5_str(vec_5x5: &[&str], first_line_num: usize) -> AocResult<BingoCard> {
+    let mut new_card = BingoCard {
+        card: [
+            [
+                BingoSlot {
+                    val: 0,
+                    marked: false,
+                };
+                5
+            ];
+            5
+        ],
+        is_winner: false,
+    };
+
+    for i in 0..5 {
+        let line_str = vec_5x5[i].split_whitespace().collect::<Vec<&str>>();
+
+        if line_str.len() != 5 {
+            return Err(AocError::parse(first_line_num + i, 1, format!("expected 5 numbers in a bingo row, found {}", line_str.len())));
+        }
+
+        for j in 0..5 {
+            new_card.card[i][j].val = error::parse_token::<u32>(line_str[j], vec_5x5[i], first_line_num + i)?;
+        }
+    }
+
+    return Ok(new_card);
+}
+
+// Get the list of calls and the bingo cards from the input
+pub fn parse_bingo(input: &str) -> AocResult<(Vec<u32>, Vec<BingoCard>)> {
+    let lines = input.lines().collect::<Vec<&str>>();
+
+    if lines.is_empty() {
+        return Err(AocError::Input("no bingo calls".to_string()));
+    }
+
+    let calls_str_vec = lines[0].split(",").collect::<Vec<&str>>();
+
+    let mut calls_vec = Vec::<u32>::new();
+
+    for call in calls_str_vec {
+        calls_vec.push(error::parse_token::<u32>(call, lines[0], 1)?);
+    }
+
+    if lines.len() > 1 && !lines[1].is_empty() {
+        return Err(AocError::parse(2, 1, "expected a blank line after the calls"));
+    }
+
+    // Create bingo cards, each separated by a blank line
+    let mut cards = Vec::<BingoCard>::new();
+    let mut line_index = 2;
+
+    while lines.len() >= line_index + 5 {
+        cards.push(create_bingo_card_from_5x5_str(&lines[line_index..line_index + 5], line_index + 1)?);
+        line_index += 5;
+
+        if line_index < lines.len() {
+            if !lines[line_index].is_empty() {
+                return Err(AocError::parse(line_index + 1, 1, "expected a blank line between bingo cards"));
+            }
+
+            line_index += 1;
+        }
+    }
+
+    if line_index < lines.len() {
+        return Err(AocError::parse(line_index + 1, 1, "incomplete bingo card"));
+    }
+
+    return Ok((calls_vec, cards));
+}
+
+pub fn print_bingo_card(bingo_card: &BingoCard) {
+    for line in bingo_card.card {
+        for slot in line {
+            if slot.marked {
+                eprint!("[{}]\t", slot.val);
+            }
+            else {
+                eprint!("{}\t", slot.val);
+            }
+        }
+        eprintln!();
+    }
+}
+
+pub fn add_call(call_num: u32, bingo_card: &mut BingoCard) {
+    for i in 0..5 {
+        for j in 0..5 {
+            if bingo_card.card[i][j].val == call_num {
+                bingo_card.card[i][j].marked = true;
+            }
+        }
+    }
+}
+
+pub fn check_if_winner(bingo_card: &BingoCard) -> bool {
+    // Check horizontal
+    for i in 0..5 {
+        let mut is_winner = true;
+
+        for j in 0..5 {
+            if !bingo_card.card[i][j].marked {
+                is_winner = false;
+                break;
+            }
+        }
+
+        if is_winner {
+            return true;
+        }
+    }
+
+    // Check vertical
+    for j in 0..5 {
+        let mut is_winner = true;
+
+        for i in 0..5 {
+            if !bingo_card.card[i][j].marked {
+                is_winner = false;
+                break;
+            }
+        }
+
+        if is_winner {
+            return true;
+        }
+    }
+
+    return false;
+}
+
+pub fn calculate_score(bingo_card: &BingoCard, last_call: u32) -> u32 {
+    let mut unmarked_sum = 0;
+
+    for i in 0..5 {
+        for j in 0..5 {
+            if !bingo_card.card[i][j].marked {
+                unmarked_sum += bingo_card.card[i][j].val;
+            }
+        }
+    }
+
+    return unmarked_sum * last_call;
+}
+
+// Run through calls until a card gets bingo, return it and the winning call
+pub fn find_first_winner(calls: &[u32], cards: &mut [BingoCard]) -> Option<(BingoCard, u32)> {
+    for &call in calls {
+        for i in 0..cards.len() {
+            add_call(call, &mut cards[i]);
+
+            if check_if_winner(&cards[i]) {
+                return Some((cards[i].clone(), call));
+            }
+        }
+    }
+
+    return None;
+}
+
+// Run through calls and mark cards as they get bingo, return the last one to win and its call
+pub fn find_last_winner(calls: &[u32], cards: &mut [BingoCard]) -> Option<(BingoCard, u32)> {
+    let mut win_counter = 0;
+    for &call in calls {
+        for i in 0..cards.len() {
+            if !cards[i].is_winner {
+                add_call(call, &mut cards[i]);
+
+                if check_if_winner(&cards[i]) {
+                    cards[i].is_winner = true;
+                    win_counter += 1;
+
+                    if win_counter == cards.len() {
+                        return Some((cards[i].clone(), call));
+                    }
+                }
+            }
+        }
+    }
+
+    return None;
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    let (calls, mut cards) = parse_bingo(input)?;
+
+    match find_first_winner(&calls, &mut cards) {
+        Some((card, call)) => return Ok(calculate_score(&card, call)),
+        None => return Err(AocError::NoSolution("no card gets bingo".to_string())),
+    }
+}
+
+pub fn part2(input: &str) -> AocResult<u32> {
+    let (calls, mut cards) = parse_bingo(input)?;
+
+    match find_last_winner(&calls, &mut cards) {
+        Some((card, call)) => return Ok(calculate_score(&card, call)),
+        None => return Err(AocError::NoSolution("not every card gets bingo".to_string())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day04.txt");
+
+    #[test]
+    fn parses_cards() {
+        let (calls, cards) = parse_bingo(EXAMPLE).unwrap();
+        assert_eq!(27, calls.len());
+        assert_eq!(3, cards.len());
+        assert_eq!(22, cards[0].card[0][0].val);
+        assert_eq!(7, cards[2].card[4][4].val);
+    }
+
+    #[test]
+    fn wins_on_rows_and_columns_only() {
+        let (_, cards) = parse_bingo(EXAMPLE).unwrap();
+
+        let mut card = cards[0].clone();
+        for call in [22, 2, 14, 18, 19] {
+            add_call(call, &mut card);
+        }
+        assert!(!check_if_winner(&card));
+
+        let mut card = cards[0].clone();
+        for call in [13, 2, 9, 10, 12] {
+            add_call(call, &mut card);
+        }
+        assert!(check_if_winner(&card));
+    }
+
+    #[test]
+    fn finds_winners() {
+        let (calls, mut cards) = parse_bingo(EXAMPLE).unwrap();
+        let (card, call) = find_first_winner(&calls, &mut cards).unwrap();
+        assert_eq!((188, 24), (calculate_score(&card, 1), call));
+
+        let (calls, mut cards) = parse_bingo(EXAMPLE).unwrap();
+        let (card, call) = find_last_winner(&calls, &mut cards).unwrap();
+        assert_eq!((148, 13), (calculate_score(&card, 1), call));
+    }
+
+    #[test]
+    fn rejects_short_rows() {
+        let input = EXAMPLE.replacen("22 13 17 11  0", "22 13 17 11", 1);
+        assert!(matches!(parse_bingo(&input), Err(AocError::Parse { line: 3, .. })));
+    }
+}
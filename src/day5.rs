@@ -0,0 +1,202 @@
+// Day 5: Hydrothermal Venture
+
+use crate::error::{self, AocError, AocResult};
+
+#[derive(Copy, Clone)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+#[derive(Copy, Clone)]
+pub struct Line {
+    pub p1: Point,
+    pub p2: Point,
+}
+
+// Get a point from an "x,y" string sliced out of the given line
+fn extract_point_from_str(point_str: &str, line_str: &str, line_num: usize) -> AocResult<Point> {
+    let coord_vec = point_str.split(',').collect::<Vec<&str>>();
+
+    if coord_vec.len() != 2 {
+        return Err(AocError::parse(line_num, error::column_of(line_str, point_str), format!("expected a point as x,y, found \"{}\"", point_str)));
+    }
+
+    let point = Point {
+        x: error::parse_token::<i32>(coord_vec[0], line_str, line_num)?,
+        y: error::parse_token::<i32>(coord_vec[1], line_str, line_num)?,
+    };
+
+    if point.x < 0 || point.y < 0 {
+        return Err(AocError::parse(line_num, error::column_of(line_str, point_str), "coordinates must not be negative"));
+    }
+
+    return Ok(point);
+}
+
+pub fn extract_line_from_str(line_str: &str, line_num: usize) -> AocResult<Line> {
+    let point_vec = line_str.split(" -> ").collect::<Vec<&str>>();
+
+    if point_vec.len() != 2 {
+        return Err(AocError::parse(line_num, 1, "expected a line as x1,y1 -> x2,y2"));
+    }
+
+    return Ok(Line {
+        p1: extract_point_from_str(point_vec[0], line_str, line_num)?,
+        p2: extract_point_from_str(point_vec[1], line_str, line_num)?,
+    });
+}
+
+pub fn is_horizontal(line: &Line) -> bool {
+    return line.p1.y == line.p2.y;
+}
+
+pub fn is_vertical(line: &Line) -> bool {
+    return line.p1.x == line.p2.x;
+}
+
+pub fn is_downward_diag(line: &Line) -> bool {
+    return (line.p2.x - line.p1.x) == (line.p2.y - line.p1.y);
+}
+
+pub fn is_upward_diag(line: &Line) -> bool {
+    return (line.p2.x - line.p1.x) == (line.p1.y - line.p2.y);
+}
+
+// Extract lines, keeping only horizontal and vertical ones unless diagonals are included
+pub fn parse_lines(input: &str, include_diagonals: bool) -> AocResult<Vec<Line>> {
+    let mut lines = Vec::<Line>::new();
+
+    for (line_num, line_str) in error::numbered_lines(input) {
+        let new_line = extract_line_from_str(line_str, line_num)?;
+
+        if is_horizontal(&new_line) || is_vertical(&new_line) ||
+           (include_diagonals && (is_downward_diag(&new_line) || is_upward_diag(&new_line))) {
+            lines.push(new_line);
+        }
+    }
+
+    return Ok(lines);
+}
+
+// Draw every line onto a grid and count the points where at least two overlap
+pub fn count_overlapping_points(lines: &[Line]) -> u32 {
+    // Find the largest x and y vals to determine diagram size
+    let mut largest_x = 0;
+    let mut largest_y = 0;
+
+    for line in lines {
+        if line.p1.x > largest_x {
+            largest_x = line.p1.x;
+        }
+
+        if line.p1.y > largest_y {
+            largest_y = line.p1.y;
+        }
+
+        if line.p2.x > largest_x {
+            largest_x = line.p2.x;
+        }
+
+        if line.p2.y > largest_y {
+            largest_y = line.p2.y;
+        }
+    }
+
+    // Fill out the grid
+    let mut vent_grid = vec![vec![0; (largest_y + 1) as usize]; (largest_x + 1) as usize];
+
+    for line in lines {
+        if is_horizontal(line) {
+            if line.p2.x > line.p1.x {
+                for i in (line.p1.x)..=(line.p2.x) {
+                    vent_grid[i as usize][line.p1.y as usize] += 1;
+                }
+            }
+            else {
+                for i in (line.p2.x)..=(line.p1.x) {
+                    vent_grid[i as usize][line.p1.y as usize] += 1;
+                }
+            }
+        }
+        else if is_vertical(line) {
+            if line.p2.y > line.p1.y {
+                for i in (line.p1.y)..=(line.p2.y) {
+                    vent_grid[line.p1.x as usize][i as usize] += 1;
+                }
+            }
+            else {
+                for i in (line.p2.y)..=(line.p1.y) {
+                    vent_grid[line.p1.x as usize][i as usize] += 1;
+                }
+            }
+        }
+        else if is_downward_diag(line) {
+            if line.p2.x > line.p1.x {
+                for i in 0..=(line.p2.x - line.p1.x) {
+                    vent_grid[(i + line.p1.x) as usize][(i + line.p1.y) as usize] += 1;
+                }
+            }
+            else {
+                for i in 0..=(line.p1.x - line.p2.x) {
+                    vent_grid[(i + line.p2.x) as usize][(i + line.p2.y) as usize] += 1;
+                }
+            }
+        }
+        else if line.p2.x > line.p1.x {
+            for i in 0..=(line.p2.x - line.p1.x) {
+                vent_grid[(i + line.p1.x) as usize][(line.p1.y - i) as usize] += 1;
+            }
+        }
+        else {
+            for i in 0..=(line.p1.x - line.p2.x) {
+                vent_grid[(i + line.p2.x) as usize][(line.p2.y - i) as usize] += 1;
+            }
+        }
+    }
+
+    // Find number of overlapping
+    let mut num_overlapping = 0;
+    for i in 0..=largest_x {
+        for j in 0..=largest_y {
+            if vent_grid[i as usize][j as usize] >= 2 {
+                num_overlapping += 1;
+            }
+        }
+    }
+
+    return num_overlapping;
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    return Ok(count_overlapping_points(&parse_lines(input, false)?));
+}
+
+pub fn part2(input: &str) -> AocResult<u32> {
+    return Ok(count_overlapping_points(&parse_lines(input, true)?));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day05.txt");
+
+    #[test]
+    fn keeps_straight_lines_unless_diagonals_included() {
+        assert_eq!(6, parse_lines(EXAMPLE, false).unwrap().len());
+        assert_eq!(10, parse_lines(EXAMPLE, true).unwrap().len());
+    }
+
+    #[test]
+    fn counts_overlaps() {
+        assert_eq!(5, count_overlapping_points(&parse_lines(EXAMPLE, false).unwrap()));
+        assert_eq!(12, count_overlapping_points(&parse_lines(EXAMPLE, true).unwrap()));
+    }
+
+    #[test]
+    fn rejects_negative_coordinates() {
+        assert!(matches!(extract_line_from_str("0,-1 -> 2,2", 1), Err(AocError::Parse { line: 1, column: 1, .. })));
+        assert!(matches!(extract_line_from_str("0,1 => 2,2", 1), Err(AocError::Parse { .. })));
+    }
+}
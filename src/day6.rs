@@ -0,0 +1,125 @@
+// Day 6: Lanternfish
+
+use crate::error::{self, AocError, AocResult};
+
+pub fn parse_fish_state(input: &str) -> AocResult<Vec<u32>> {
+    let lines = input.lines().collect::<Vec<&str>>();
+
+    if lines.is_empty() || lines[0].trim().is_empty() {
+        return Err(AocError::Input("no fish given".to_string()));
+    }
+
+    let fish_state_strs = lines[0].split(",").collect::<Vec<&str>>();
+    let mut fish_state = Vec::<u32>::new();
+
+    for fish in fish_state_strs {
+        let fish_wait = error::parse_token::<u32>(fish, lines[0], 1)?;
+
+        if fish_wait > 8 {
+            return Err(AocError::parse(1, error::column_of(lines[0], fish), format!("fish timer {} is over 8", fish_wait)));
+        }
+
+        fish_state.push(fish_wait);
+    }
+
+    return Ok(fish_state);
+}
+
+// Simulate every fish one day at a time
+pub fn simulate_fish(initial_state: &[u32], num_days: u32) -> usize {
+    let mut fish_state = initial_state.to_vec();
+
+    for _ in 0..num_days {
+        for i in 0..fish_state.len() {
+            if fish_state[i] == 0 {
+                fish_state[i] = 6;
+                fish_state.push(8);
+            }
+            else {
+                fish_state[i] -= 1;
+            }
+        }
+    }
+
+    return fish_state.len();
+}
+
+// Recursively get offspring number given days left and days before first double
+pub fn get_num_fish_descendants(num_days_left: i32, initial_state: i32) -> u64 {
+    // Start with current fish
+    let mut total_fish: u64 = 1;
+
+    let mut day_countdown: i32 = num_days_left - initial_state;
+
+    while day_countdown > 0 {
+        total_fish += get_num_fish_descendants(day_countdown, 9);
+        day_countdown -= 7;
+    }
+
+    return total_fish;
+}
+
+// Bin each fish to wait times so each is only calculated once
+pub fn bin_fish_by_wait(fish_state: &[u32]) -> Vec<u64> {
+    let longest_fish_wait = *fish_state.iter().max().unwrap();
+    let mut fish_bins = vec![0; (longest_fish_wait + 1) as usize];
+
+    for &fish_wait in fish_state {
+        fish_bins[fish_wait as usize] += 1;
+    }
+
+    return fish_bins;
+}
+
+pub fn count_fish_descendants(fish_state: &[u32], num_days: i32) -> u64 {
+    let fish_bins = bin_fish_by_wait(fish_state);
+    let mut total_fish = 0;
+
+    for i in 0..fish_bins.len() {
+        if fish_bins[i] > 0 {
+            total_fish += get_num_fish_descendants(num_days, i as i32) * fish_bins[i];
+        }
+    }
+
+    return total_fish;
+}
+
+pub fn part1(input: &str) -> AocResult<usize> {
+    return Ok(simulate_fish(&parse_fish_state(input)?, 80));
+}
+
+pub fn part2(input: &str) -> AocResult<u64> {
+    return Ok(count_fish_descendants(&parse_fish_state(input)?, 256));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day06.txt");
+
+    #[test]
+    fn simulates_fish() {
+        let fish_state = parse_fish_state(EXAMPLE).unwrap();
+        assert_eq!(26, simulate_fish(&fish_state, 18));
+        assert_eq!(5934, simulate_fish(&fish_state, 80));
+    }
+
+    #[test]
+    fn counts_descendants_like_simulation() {
+        let fish_state = parse_fish_state(EXAMPLE).unwrap();
+        assert_eq!(26, count_fish_descendants(&fish_state, 18));
+        assert_eq!(5934, count_fish_descendants(&fish_state, 80));
+    }
+
+    #[test]
+    #[ignore = "takes around a minute"]
+    fn counts_descendants_after_256_days() {
+        assert_eq!(26984457539, count_fish_descendants(&parse_fish_state(EXAMPLE).unwrap(), 256));
+    }
+
+    #[test]
+    fn rejects_long_timers() {
+        assert_eq!(AocError::parse(1, 3, "fish timer 9 is over 8"), parse_fish_state("3,9,1\n").unwrap_err());
+    }
+}
@@ -0,0 +1,89 @@
+// Day 7: The Treachery of Whales
+
+use crate::error::{self, AocError, AocResult};
+
+pub fn parse_crab_positions(input: &str) -> AocResult<Vec<i32>> {
+    let lines = input.lines().collect::<Vec<&str>>();
+
+    if lines.is_empty() || lines[0].trim().is_empty() {
+        return Err(AocError::Input("no crab positions given".to_string()));
+    }
+
+    let crab_pos_strs = lines[0].split(",").collect::<Vec<&str>>();
+    let mut crab_posns = Vec::<i32>::new();
+
+    for crab_str in crab_pos_strs {
+        crab_posns.push(error::parse_token::<i32>(crab_str, lines[0], 1)?);
+    }
+
+    return Ok(crab_posns);
+}
+
+// Get fuel needed for all crabs to move to the median location
+pub fn get_fuel_to_median(crab_positions: &[i32]) -> i32 {
+    let mut crab_posns = crab_positions.to_vec();
+
+    // Find the median location
+    crab_posns.sort();
+    let midpoint = crab_posns[crab_posns.len() / 2];
+
+    // Get fuel needed to move to mean location
+    let mut total_fuel = 0;
+
+    for crab_pos in crab_posns {
+        total_fuel += (midpoint - crab_pos).abs();
+    }
+
+    return total_fuel;
+}
+
+// Get fuel needed to move to the best location when each step costs one more than the last
+pub fn get_weighted_fuel(crab_posns: &[i32]) -> i32 {
+    let min_position = *crab_posns.iter().min().unwrap();
+    let max_position = *crab_posns.iter().max().unwrap();
+
+    // Get fuel needed to move to best location
+    let mut min_total_fuel = i32::MAX;
+
+    for midpoint in min_position..=max_position {
+        let mut total_fuel = 0;
+
+        for crab_pos in crab_posns {
+            let distance = (midpoint - crab_pos).abs();
+            total_fuel += (1 + distance) * distance / 2;
+        }
+
+        if total_fuel < min_total_fuel {
+            min_total_fuel = total_fuel;
+        }
+    }
+
+    return min_total_fuel;
+}
+
+pub fn part1(input: &str) -> AocResult<i32> {
+    return Ok(get_fuel_to_median(&parse_crab_positions(input)?));
+}
+
+pub fn part2(input: &str) -> AocResult<i32> {
+    return Ok(get_weighted_fuel(&parse_crab_positions(input)?));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day07.txt");
+
+    #[test]
+    fn gets_fuel() {
+        let crab_posns = parse_crab_positions(EXAMPLE).unwrap();
+        assert_eq!(37, get_fuel_to_median(&crab_posns));
+        assert_eq!(168, get_weighted_fuel(&crab_posns));
+    }
+
+    #[test]
+    fn rejects_empty_input() {
+        assert!(matches!(parse_crab_positions(""), Err(AocError::Input(_))));
+    }
+}
@@ -0,0 +1,386 @@
+// Day 8: Seven Segment Search
+
+use crate::error::{self, AocError, AocResult};
+
+#[derive(Copy, Clone)]
+pub struct DigitCal {
+    pub top: char,
+    pub top_left: char,
+    pub top_right: char,
+    pub middle: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+    pub bottom: char,
+}
+
+pub fn print_digit_cal(digit_cal: &DigitCal) {
+    eprint!(" ");
+    for _ in 0..4 {
+        eprint!("{}", digit_cal.top);
+    }
+    eprintln!();
+
+    eprintln!("{}    {}", digit_cal.top_left, digit_cal.top_right);
+    eprintln!("{}    {}", digit_cal.top_left, digit_cal.top_right);
+
+    eprint!(" ");
+    for _ in 0..4 {
+        eprint!("{}", digit_cal.middle);
+    }
+    eprintln!();
+
+    eprintln!("{}    {}", digit_cal.bottom_left, digit_cal.bottom_right);
+    eprintln!("{}    {}", digit_cal.bottom_left, digit_cal.bottom_right);
+
+    eprint!(" ");
+    for _ in 0..4 {
+        eprint!("{}", digit_cal.bottom);
+    }
+    eprintln!();
+    eprintln!();
+}
+
+pub fn calibrate_digit(calibration_values: &[&str]) -> AocResult<DigitCal> {
+    let mut digit_cal = DigitCal {
+        top : '.',
+        top_left: '.',
+        top_right: '.',
+        middle: '.',
+        bottom_left: '.',
+        bottom_right: '.',
+        bottom: '.',
+    };
+
+    let mut char_list = vec!{'a', 'b', 'c', 'd', 'e', 'f', 'g'};
+
+    // Start by finding the 1
+    let mut one_str: &str = "";
+
+    for val in calibration_values {
+        if val.len() == 2 {
+            one_str = val;
+            break;
+        }
+    }
+
+    if one_str.len() != 2 {
+        return Err(AocError::Input("no 1 found in calibration".to_string()));
+    }
+
+    // Next find the 7
+    let mut seven_str: &str = "";
+
+    for val in calibration_values {
+        if val.len() == 3 {
+            seven_str = val;
+            break;
+        }
+    }
+
+    if seven_str.len() != 3 {
+        return Err(AocError::Input("no 7 found in calibration".to_string()));
+    }
+
+    // Determine the top character
+    for letter in seven_str.chars() {
+        if !one_str.contains(letter) {
+            digit_cal.top = letter;
+            char_list.retain(|l| { return l != &letter; });
+            break;
+        }
+    }
+
+    // Find the 4
+    let mut four_str: &str = "";
+
+    for val in calibration_values {
+        if val.len() == 4 {
+            four_str = val;
+            break;
+        }
+    }
+
+    if four_str.len() != 4 {
+        return Err(AocError::Input("no 4 found in calibration".to_string()));
+    }
+
+    // Find the 6 - size 6 + one of the 1 components is missing
+    // And the 9 - size 6 + contains all of 4's values
+    // And the 0 - size 6 but not the 6 or the 9
+    // This will determine the right side vals at the same time
+    let mut six_str: &str = "";
+    let mut nine_str: &str = "";
+    let mut zero_str: &str = "";
+    let right_letter_1 = one_str.chars().next().unwrap();
+    let right_letter_2 = one_str.chars().nth(1).unwrap();
+
+    for val in calibration_values {
+        if val.len() == 6 {
+            if !val.contains(right_letter_1) {
+                six_str = val;
+                digit_cal.top_right = right_letter_1;
+                digit_cal.bottom_right = right_letter_2;
+                char_list.retain(|l| { return l != &right_letter_1 && l != &right_letter_2; });
+            }
+            else if !val.contains(right_letter_2) {
+                six_str = val;
+                digit_cal.top_right = right_letter_2;
+                digit_cal.bottom_right = right_letter_1;
+                char_list.retain(|l| { return l != &right_letter_1 && l != &right_letter_2; });
+            }
+            else {
+                // 9 already found - this is 0
+                if nine_str.len() == 6 {
+                    zero_str = val;
+                }
+
+                // 0 already found - this is 9
+                else if zero_str.len() == 6 {
+                    nine_str = val;
+                }
+
+                // Determine whether the val is 0 or 9
+                else {
+                    for letter in four_str.chars() {
+                        if !val.contains(letter) {
+                            zero_str = val;
+                            break;
+                        }
+                    }
+
+                    if zero_str.len() != 6 {
+                        nine_str = val;
+                    }
+                }
+            }
+        }
+    }
+
+    if six_str.len() != 6 {
+        return Err(AocError::Input("no 6 found in calibration".to_string()));
+    }
+
+    if zero_str.len() != 6 {
+        return Err(AocError::Input("no 0 found in calibration".to_string()));
+    }
+
+    if nine_str.len() != 6 {
+        return Err(AocError::Input("no 9 found in calibration".to_string()));
+    }
+
+    // Find the middle - in 4 but not 0
+    for letter in four_str.chars() {
+        if !zero_str.contains(letter) {
+            digit_cal.middle = letter;
+            char_list.retain(|l| { return l != &letter; });
+            break;
+        }
+    }
+
+    // Find the 3 - length 5 containing both 1 chars
+    let mut three_str: &str = "";
+
+    for val in calibration_values {
+        if val.len() == 5 && val.contains(right_letter_1) && val.contains(right_letter_2) {
+            three_str = val;
+            break;
+        }
+    }
+
+    if three_str.len() != 5 {
+        return Err(AocError::Input("no 3 found in calibration".to_string()));
+    }
+
+    // Find top left - in 9 but not 3
+    for letter in nine_str.chars() {
+        if !three_str.contains(letter) {
+            digit_cal.top_left = letter;
+            char_list.retain(|l| { return l != &letter; });
+            break;
+        }
+    }
+
+    // Find the bottom - only char in 9 not accounted for
+    for letter in nine_str.chars() {
+        if letter != digit_cal.top && letter != digit_cal.top_left &&
+           letter != digit_cal.top_right && letter != digit_cal.middle && 
+           letter != digit_cal.bottom_right {
+            digit_cal.bottom = letter;
+            char_list.retain(|l| { return l != &letter; });
+            break;
+        }
+    }
+
+    // Find the bottom left - the only remaining letter
+    if char_list.len() != 1 {
+        return Err(AocError::Input("calibration does not use each segment once".to_string()));
+    }
+
+    digit_cal.bottom_left = char_list[0];
+
+    return Ok(digit_cal);
+}
+
+pub fn extract_digit(digit_str: &str, calibration: DigitCal) -> AocResult<i32> {
+    match digit_str.len() {
+        2 => return Ok(1),
+        3 => return Ok(7),
+        4 => return Ok(4),
+        5 => {
+            if digit_str.contains(calibration.top_left) {
+                return Ok(5);
+            }
+            else if digit_str.contains(calibration.bottom_left) {
+                return Ok(2);
+            }
+            else {
+                return Ok(3);
+            }
+        },
+        6 => {
+            if !digit_str.contains(calibration.middle) {
+                return Ok(0);
+            }
+            else if digit_str.contains(calibration.bottom_left) {
+                return Ok(6);
+            }
+            else {
+                return Ok(9);
+            }
+        },
+        7 => return Ok(8),
+        _ => return Err(AocError::Input(format!("bad digit \"{}\"", digit_str))),
+    }
+}
+
+// A display's ten calibration patterns and four output digits, along with the line they came from
+pub struct Entry<'a> {
+    pub line_num: usize,
+    pub calibration_values: Vec<&'a str>,
+    pub digit_values: Vec<&'a str>,
+}
+
+// Split an entry into its ten calibration patterns and four output digits
+pub fn split_entry(line: &str, line_num: usize) -> AocResult<Entry<'_>> {
+    let split_input = line.split(" | ").collect::<Vec<&str>>();
+
+    if split_input.len() != 2 {
+        return Err(AocError::parse(line_num, 1, "expected patterns and output separated by \" | \""));
+    }
+
+    let calibration_values = split_input[0].split_whitespace().collect::<Vec<&str>>();
+    let digit_values = split_input[1].split_whitespace().collect::<Vec<&str>>();
+
+    if calibration_values.len() != 10 {
+        return Err(AocError::parse(line_num, 1, format!("expected 10 patterns, found {}", calibration_values.len())));
+    }
+
+    if digit_values.len() != 4 {
+        return Err(AocError::parse(line_num, error::column_of(line, split_input[1]), format!("expected 4 output digits, found {}", digit_values.len())));
+    }
+
+    for value in calibration_values.iter().chain(digit_values.iter()) {
+        if let Some(offset) = value.find(|c: char| !('a'..='g').contains(&c)) {
+            return Err(AocError::parse(line_num, error::column_of(line, value) + offset, "segments must be a to g"));
+        }
+    }
+
+    return Ok(Entry {
+        line_num,
+        calibration_values,
+        digit_values,
+    });
+}
+
+pub fn parse_entries(input: &str) -> AocResult<Vec<Entry<'_>>> {
+    let mut entries = Vec::<Entry>::new();
+
+    for (line_num, line) in error::numbered_lines(input) {
+        entries.push(split_entry(line, line_num)?);
+    }
+
+    return Ok(entries);
+}
+
+// Count the output digits that can be identified by their number of segments alone
+pub fn count_simple_digits(entries: &[Entry]) -> u32 {
+    let mut digit_counter_array: [u32; 10] = [0; 10];
+
+    for entry in entries {
+        for digit_value in &entry.digit_values {
+            match digit_value.len() {
+                2 => digit_counter_array[1] += 1,
+                4 => digit_counter_array[4] += 1,
+                3 => digit_counter_array[7] += 1,
+                7 => digit_counter_array[8] += 1,
+                _ => (),
+            }
+        }
+    }
+
+    return digit_counter_array.iter().sum::<u32>();
+}
+
+// Calibrate the display and decode its four digit output value
+pub fn decode_output_value(entry: &Entry) -> AocResult<i32> {
+    let digit_cal = calibrate_digit(&entry.calibration_values).map_err(|err| err.on_line(entry.line_num))?;
+    let mut current_val = 0;
+    let mut multiplier = 1000;
+
+    for digit in &entry.digit_values {
+        current_val += extract_digit(digit, digit_cal).map_err(|err| err.on_line(entry.line_num))? * multiplier;
+        multiplier /= 10;
+    }
+
+    return Ok(current_val);
+}
+
+pub fn sum_output_values(entries: &[Entry]) -> AocResult<i32> {
+    let mut overall_count = 0;
+
+    for entry in entries {
+        overall_count += decode_output_value(entry)?;
+    }
+
+    return Ok(overall_count);
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    return Ok(count_simple_digits(&parse_entries(input)?));
+}
+
+pub fn part2(input: &str) -> AocResult<i32> {
+    return sum_output_values(&parse_entries(input)?);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day08.txt");
+
+    const SINGLE_ENTRY: &str = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbaf";
+
+    #[test]
+    fn calibrates_digit() {
+        let entry = split_entry(SINGLE_ENTRY, 1).unwrap();
+        let digit_cal = calibrate_digit(&entry.calibration_values).unwrap();
+
+        assert_eq!(['d', 'e', 'a', 'f', 'g', 'b', 'c'], [digit_cal.top, digit_cal.top_left, digit_cal.top_right,
+            digit_cal.middle, digit_cal.bottom_left, digit_cal.bottom_right, digit_cal.bottom]);
+        assert_eq!(5, extract_digit("cdfeb", digit_cal).unwrap());
+        assert_eq!(3, extract_digit("fcadb", digit_cal).unwrap());
+    }
+
+    #[test]
+    fn decodes_output_values() {
+        assert_eq!(5353, decode_output_value(&split_entry(SINGLE_ENTRY, 1).unwrap()).unwrap());
+        assert_eq!(26, count_simple_digits(&parse_entries(EXAMPLE).unwrap()));
+    }
+
+    #[test]
+    fn reports_bad_segments() {
+        let entry = SINGLE_ENTRY.replace("dab", "dah");
+        assert_eq!(AocError::parse(3, 29, "segments must be a to g"), split_entry(&entry, 3).err().unwrap());
+    }
+}
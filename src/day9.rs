@@ -0,0 +1,105 @@
+// Day 9: Smoke Basin
+
+use crate::error::{AocError, AocResult};
+use crate::grid::Grid;
+
+pub fn build_heightmap(input: &str) -> AocResult<Grid<u32>> {
+    return Grid::parse_digits(input, "height");
+}
+
+fn is_local_min(x: usize, y: usize, heightmap: &Grid<u32>) -> bool {
+    return heightmap.neighbors4(x, y).all(|neighbor| heightmap[(x, y)] < heightmap[neighbor]);
+}
+
+// Sum the risk levels of every local min in a heightmap
+pub fn get_total_risk(heightmap: &Grid<u32>) -> u32 {
+    let mut total_risk = 0;
+
+    // Find local mins
+    for y in 0..heightmap.height() {
+        for x in 0..heightmap.width() {
+            if is_local_min(x, y, heightmap) {
+                total_risk += heightmap[(x, y)] + 1;
+            }
+        }
+    }
+
+    return total_risk;
+}
+
+pub fn get_basin_size(x: usize, y: usize, heightmap: &mut Grid<u32>) -> u32 {
+    // Include current spot and set it to 9 to mark it done
+    let mut basin_size = 1;
+    heightmap[(x, y)] = 9;
+
+    for neighbor in heightmap.neighbors4(x, y) {
+        if heightmap[neighbor] < 9 {
+            basin_size += get_basin_size(neighbor.0, neighbor.1, heightmap);
+        }
+    }
+
+    return basin_size;
+}
+
+// Get the size of every basin from largest to smallest
+pub fn get_basin_sizes(heightmap: &mut Grid<u32>) -> Vec<u32> {
+    let mut basin_sizes = Vec::<u32>::new();
+
+    // Find local mins and their basins
+    for y in 0..heightmap.height() {
+        for x in 0..heightmap.width() {
+            if is_local_min(x, y, heightmap) {
+                // Find size of this basin, replace vals with 9 to eliminate them
+                basin_sizes.push(get_basin_size(x, y, heightmap));
+            }
+        }
+    }
+
+    basin_sizes.sort();
+    basin_sizes.reverse();
+
+    return basin_sizes;
+}
+
+pub fn part1(input: &str) -> AocResult<u32> {
+    return Ok(get_total_risk(&build_heightmap(input)?));
+}
+
+pub fn part2(input: &str) -> AocResult<u32> {
+    let basin_sizes = get_basin_sizes(&mut build_heightmap(input)?);
+
+    if basin_sizes.len() < 3 {
+        return Err(AocError::NoSolution(format!("only {} basins found", basin_sizes.len())));
+    }
+
+    let mut basin_multiplier = 1;
+
+    for i in 0..3 {
+        basin_multiplier *= basin_sizes[i];
+    }
+
+    return Ok(basin_multiplier);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../files/examples/day09.txt");
+
+    #[test]
+    fn gets_total_risk() {
+        assert_eq!(15, get_total_risk(&build_heightmap(EXAMPLE).unwrap()));
+    }
+
+    #[test]
+    fn gets_basin_sizes() {
+        assert_eq!(vec![14, 9, 9, 3], get_basin_sizes(&mut build_heightmap(EXAMPLE).unwrap()));
+    }
+
+    #[test]
+    fn rejects_bad_heights() {
+        assert_eq!(AocError::parse(2, 2, "bad height 'x'"), build_heightmap("123\n4x6\n").unwrap_err());
+        assert!(matches!(build_heightmap("123\n45\n"), Err(AocError::Parse { line: 2, .. })));
+    }
+}
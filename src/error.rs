@@ -0,0 +1,107 @@
+// Errors shared by every day's parsing and solving
+
+use std::error;
+use std::fmt;
+use std::process;
+use std::str::FromStr;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AocError {
+    // Input could not be parsed, line and column are counted from 1
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+
+    // Input was readable but is missing something or is inconsistent as a whole
+    Input(String),
+
+    // Input was valid but there is no answer for it
+    NoSolution(String),
+}
+
+pub type AocResult<T> = Result<T, AocError>;
+
+impl AocError {
+    pub fn parse(line: usize, column: usize, message: impl Into<String>) -> AocError {
+        return AocError::Parse {
+            line,
+            column,
+            message: message.into(),
+        };
+    }
+
+    // Attach a line number to an error found while handling a single line
+    pub fn on_line(self, line: usize) -> AocError {
+        match self {
+            AocError::Input(message) => return AocError::parse(line, 1, message),
+            _ => return self,
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AocError::Parse { line, column, message } => write!(f, "line {}, column {}: {}", line, column, message),
+            AocError::Input(message) => write!(f, "bad input: {}", message),
+            AocError::NoSolution(message) => write!(f, "no solution: {}", message),
+        }
+    }
+}
+
+impl error::Error for AocError {}
+
+// Iterate over the lines of the input along with their line numbers counted from 1
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    return input.lines().enumerate().map(|(i, line)| (i + 1, line));
+}
+
+// Get the column (counted from 1) of a token that was sliced out of the given line
+pub fn column_of(line: &str, token: &str) -> usize {
+    let offset = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+    return offset.min(line.len()) + 1;
+}
+
+// Parse a token sliced out of a line, reporting where it is if it is not valid
+pub fn parse_token<T: FromStr>(token: &str, line: &str, line_num: usize) -> AocResult<T> {
+    match token.trim().parse::<T>() {
+        Ok(value) => return Ok(value),
+        Err(_) => return Err(AocError::parse(line_num, column_of(line, token), format!("invalid number \"{}\"", token))),
+    }
+}
+
+// Print the error and exit, for use by the day binaries
+pub fn report_and_exit(err: impl fmt::Display) -> ! {
+    eprintln!("Error, {}", err);
+    process::exit(1);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_token_columns() {
+        let line = "fold along y=7";
+        let tokens = line.split_whitespace().collect::<Vec<&str>>();
+        assert_eq!(1, column_of(line, tokens[0]));
+        assert_eq!(12, column_of(line, tokens[2]));
+    }
+
+    #[test]
+    fn reports_bad_tokens() {
+        let line = "3,x,4";
+        let tokens = line.split(',').collect::<Vec<&str>>();
+        assert_eq!(Ok(3), parse_token::<u32>(tokens[0], line, 7));
+        assert_eq!(AocError::parse(7, 3, "invalid number \"x\""), parse_token::<u32>(tokens[1], line, 7).unwrap_err());
+    }
+
+    #[test]
+    fn attaches_lines_to_input_errors() {
+        assert_eq!(AocError::parse(4, 1, "bad"), AocError::Input("bad".to_string()).on_line(4));
+        assert_eq!(AocError::NoSolution("none".to_string()), AocError::NoSolution("none".to_string()).on_line(4));
+        assert_eq!("line 4, column 1: bad", AocError::parse(4, 1, "bad").to_string());
+    }
+}
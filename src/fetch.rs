@@ -0,0 +1,137 @@
+// Fetching puzzle input from the puzzle site, or anything serving the same paths
+//
+// Plain http:// base URLs are fetched directly so a local stand-in can be used, https:// ones go through curl
+
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+const USER_AGENT: &str = "lvoytek_advent_of_code_2021 input fetcher";
+const TIMEOUT: Duration = Duration::from_secs(30);
+
+pub fn input_url(base_url: &str, year: u32, day: u32) -> String {
+    return format!("{}/{}/day/{}/input", base_url.trim_end_matches('/'), year, day);
+}
+
+// Download a day's input using the given session token
+pub fn fetch_input(base_url: &str, session: &str, year: u32, day: u32) -> io::Result<String> {
+    let url = input_url(base_url, year, day);
+
+    if let Some(host_and_path) = url.strip_prefix("http://") {
+        return http_get(host_and_path, session);
+    }
+
+    if url.starts_with("https://") {
+        return curl_get(&url, session);
+    }
+
+    return Err(io::Error::new(io::ErrorKind::InvalidInput, format!("base URL \"{}\" must start with http:// or https://", base_url)));
+}
+
+fn http_get(host_and_path: &str, session: &str) -> io::Result<String> {
+    let (host, path) = match host_and_path.find('/') {
+        Some(path_start) => (&host_and_path[..path_start], &host_and_path[path_start..]),
+        None => (host_and_path, "/"),
+    };
+
+    let address = if host.contains(':') {host.to_string()} else {format!("{}:80", host)};
+
+    let mut stream = TcpStream::connect(address)?;
+    stream.set_read_timeout(Some(TIMEOUT))?;
+    stream.set_write_timeout(Some(TIMEOUT))?;
+
+    // HTTP/1.0 keeps the response to a plain body ending when the connection closes
+    write!(stream, "GET {} HTTP/1.0\r\nHost: {}\r\nUser-Agent: {}\r\nCookie: session={}\r\n\r\n", path, host, USER_AGENT, session)?;
+
+    let mut response = Vec::<u8>::new();
+    stream.read_to_end(&mut response)?;
+
+    let header_end = match response.windows(4).position(|window| window == b"\r\n\r\n") {
+        Some(header_end) => header_end,
+        None => return Err(io::Error::new(io::ErrorKind::InvalidData, "incomplete HTTP response")),
+    };
+
+    let headers = String::from_utf8_lossy(&response[..header_end]);
+    let status_line = headers.lines().next().unwrap_or("");
+
+    if status_line.split_whitespace().nth(1) != Some("200") {
+        return Err(io::Error::other(format!("server answered \"{}\"", status_line)));
+    }
+
+    return String::from_utf8(response[header_end + 4..].to_vec()).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err));
+}
+
+fn curl_get(url: &str, session: &str) -> io::Result<String> {
+    // Headers go in through stdin so the session token does not show up in the process list
+    let mut curl = Command::new("curl")
+        .args(["--silent", "--show-error", "--fail", "--header", "@-", url])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|err| io::Error::new(err.kind(), format!("https fetching needs curl: {}", err)))?;
+
+    if let Some(mut stdin) = curl.stdin.take() {
+        write!(stdin, "User-Agent: {}\nCookie: session={}\n", USER_AGENT, session)?;
+    }
+
+    let output = curl.wait_with_output()?;
+
+    if !output.status.success() {
+        return Err(io::Error::other(format!("curl failed: {}", String::from_utf8_lossy(&output.stderr).trim())));
+    }
+
+    return String::from_utf8(output.stdout).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::TcpListener;
+    use std::thread::{self, JoinHandle};
+
+    // Answer a single request with the given response, handing back the request that was received
+    fn serve_once(response: &'static str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let server = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = Vec::<u8>::new();
+            let mut buffer = [0; 1024];
+
+            while !request.ends_with(b"\r\n\r\n") {
+                let read_len = stream.read(&mut buffer).unwrap();
+                request.extend_from_slice(&buffer[..read_len]);
+            }
+
+            stream.write_all(response.as_bytes()).unwrap();
+            return String::from_utf8(request).unwrap();
+        });
+
+        return (base_url, server);
+    }
+
+    #[test]
+    fn fetches_from_local_server() {
+        let (base_url, server) = serve_once("HTTP/1.0 200 OK\r\nContent-Type: text/plain\r\n\r\n199\n200\n");
+
+        assert_eq!("199\n200\n", fetch_input(&base_url, "abc123", 2021, 1).unwrap());
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2021/day/1/input HTTP/1.0\r\n"));
+        assert!(request.contains("\r\nCookie: session=abc123\r\n"));
+    }
+
+    #[test]
+    fn reports_failed_requests() {
+        let (base_url, server) = serve_once("HTTP/1.0 404 Not Found\r\n\r\nNot found");
+
+        let err = fetch_input(&base_url, "abc123", 2021, 26).unwrap_err();
+        assert_eq!("server answered \"HTTP/1.0 404 Not Found\"", err.to_string());
+        server.join().unwrap();
+
+        assert_eq!(io::ErrorKind::InvalidInput, fetch_input("ftp://example.com", "abc123", 2021, 1).unwrap_err().kind());
+    }
+}
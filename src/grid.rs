@@ -0,0 +1,236 @@
+// A rectangular grid of cells shared by the map based days
+//
+// Positions are (x, y) with x counting columns from the left and y counting rows from the top
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+use std::slice;
+
+use crate::error::{AocError, AocResult};
+
+const ORTHOGONAL_OFFSETS: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const ALL_OFFSETS: [(isize, isize); 8] = [(-1, -1), (0, -1), (1, -1), (-1, 0), (1, 0), (-1, 1), (0, 1), (1, 1)];
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+// Move a position by an offset, giving None if it leaves a width x height grid
+fn offset_pos(x: usize, y: usize, dx: isize, dy: isize, width: usize, height: usize) -> Option<(usize, usize)> {
+    let new_x = x.checked_add_signed(dx)?;
+    let new_y = y.checked_add_signed(dy)?;
+
+    if new_x >= width || new_y >= height {
+        return None;
+    }
+
+    return Some((new_x, new_y));
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> where T: Clone {
+        return Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        };
+    }
+
+    // Parse one cell per character, with every line being a row of the same width
+    // cell_name describes a single cell in error messages, such as "height"
+    pub fn parse(input: &str, cell_name: &str, parse_cell: impl Fn(char) -> Option<T>) -> AocResult<Grid<T>> {
+        let mut width = 0;
+        let mut height = 0;
+        let mut cells = Vec::<T>::new();
+
+        for (line_index, line) in input.lines().enumerate() {
+            let line_width = line.chars().count();
+
+            if line_index == 0 {
+                width = line_width;
+            }
+
+            if line_width == 0 || line_width != width {
+                return Err(AocError::parse(line_index + 1, 1, format!("expected {} {}s, found {}", width, cell_name, line_width)));
+            }
+
+            for (char_index, val) in line.chars().enumerate() {
+                match parse_cell(val) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(AocError::parse(line_index + 1, char_index + 1, format!("bad {} '{}'", cell_name, val))),
+                }
+            }
+
+            height += 1;
+        }
+
+        if cells.is_empty() {
+            return Err(AocError::Input(format!("no {}s found", cell_name)));
+        }
+
+        return Ok(Grid {
+            width,
+            height,
+            cells,
+        });
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+
+    pub fn height(&self) -> usize {
+        return self.height;
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        return Some(&self.cells[y * self.width + x]);
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        return &self.cells[y * self.width..(y + 1) * self.width];
+    }
+
+    // Every cell from the top left, a row at a time
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        return self.cells.iter();
+    }
+
+    // Positions above, left, right and below that are inside the grid
+    // The iterator does not borrow the grid so cells can be changed while going through it
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        return ORTHOGONAL_OFFSETS.into_iter().filter_map(move |(dx, dy)| offset_pos(x, y, dx, dy, width, height));
+    }
+
+    // Positions in all eight directions, including diagonals, that are inside the grid
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        let (width, height) = (self.width, self.height);
+        return ALL_OFFSETS.into_iter().filter_map(move |(dx, dy)| offset_pos(x, y, dx, dy, width, height));
+    }
+
+    // Move a position by an offset, wrapping around the edges as if the grid were a torus
+    pub fn wrapping_pos(&self, x: usize, y: usize, dx: isize, dy: isize) -> (usize, usize) {
+        let new_x = (x as isize + dx).rem_euclid(self.width as isize) as usize;
+        let new_y = (y as isize + dy).rem_euclid(self.height as isize) as usize;
+
+        return (new_x, new_y);
+    }
+
+    // Build a grid of tiles_across x tiles_down copies of this one
+    // Each copied cell is made from the original cell and the column and row of its tile
+    pub fn tile(&self, tiles_across: usize, tiles_down: usize, tile_cell: impl Fn(&T, usize, usize) -> T) -> Grid<T> {
+        let width = self.width * tiles_across;
+        let height = self.height * tiles_down;
+        let mut cells = Vec::<T>::with_capacity(width * height);
+
+        for y in 0..height {
+            for x in 0..width {
+                cells.push(tile_cell(&self[(x % self.width, y % self.height)], x / self.width, y / self.height));
+            }
+        }
+
+        return Grid {
+            width,
+            height,
+            cells,
+        };
+    }
+}
+
+impl Grid<u32> {
+    // Parse a map of single digits such as a heightmap
+    pub fn parse_digits(input: &str, cell_name: &str) -> AocResult<Grid<u32>> {
+        const RADIX: u32 = 10;
+        return Grid::parse(input, cell_name, |val| val.to_digit(RADIX));
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(x < self.width && y < self.height, "position ({}, {}) is outside a {}x{} grid", x, y, self.width, self.height);
+        return &self.cells[y * self.width + x];
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(x < self.width && y < self.height, "position ({}, {}) is outside a {}x{} grid", x, y, self.width, self.height);
+        return &mut self.cells[y * self.width + x];
+    }
+}
+
+// Print a row per line with each cell's display form next to each other, the way the maps are written
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+
+            writeln!(f)?;
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_and_prints_digits() {
+        let grid = Grid::parse_digits("123\n456\n", "digit").unwrap();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!(6, grid[(2, 1)]);
+        assert_eq!(&[4, 5, 6], grid.row(1));
+        assert_eq!(None, grid.get(3, 0));
+        assert_eq!("123\n456\n", grid.to_string());
+    }
+
+    #[test]
+    fn rejects_bad_grids() {
+        assert_eq!(AocError::parse(2, 2, "bad digit 'x'"), Grid::parse_digits("123\n4x6\n", "digit").unwrap_err());
+        assert_eq!(AocError::parse(2, 1, "expected 3 digits, found 2"), Grid::parse_digits("123\n45\n", "digit").unwrap_err());
+        assert_eq!(AocError::Input("no digits found".to_string()), Grid::parse_digits("", "digit").unwrap_err());
+    }
+
+    #[test]
+    fn finds_neighbors() {
+        let grid = Grid::new(3, 3, 0);
+
+        assert_eq!(vec![(1, 0), (0, 1)], grid.neighbors4(0, 0).collect::<Vec<(usize, usize)>>());
+        assert_eq!(4, grid.neighbors4(1, 1).count());
+        assert_eq!(3, grid.neighbors8(2, 2).count());
+        assert_eq!(8, grid.neighbors8(1, 1).count());
+    }
+
+    #[test]
+    fn wraps_positions() {
+        let grid = Grid::new(4, 3, 0);
+
+        assert_eq!((0, 2), grid.wrapping_pos(3, 2, 1, 0));
+        assert_eq!((3, 2), grid.wrapping_pos(0, 0, -1, -1));
+    }
+
+    #[test]
+    fn tiles() {
+        let grid = Grid::parse_digits("12\n", "digit").unwrap();
+        let tiled = grid.tile(2, 2, |val, tile_x, tile_y| val + 10 * tile_x as u32 + 100 * tile_y as u32);
+
+        assert_eq!(&[1, 2, 11, 12], tiled.row(0));
+        assert_eq!(&[101, 102, 111, 112], tiled.row(1));
+    }
+}
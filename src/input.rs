@@ -0,0 +1,272 @@
+// Locating, reading and caching puzzle input
+//
+// Each day's input is cached as dayNN.txt, so nothing else needs to know input file names
+// Input is taken from, in order:
+//   An explicit path, with - meaning stdin
+//   The day's file in the cache directory given by AOC_INPUT_DIR
+//   The day's file in files/, relative to the working directory or else the repo root
+//
+// A day missing from the cache is fetched and cached when AOC_SESSION holds a session token
+
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use crate::fetch;
+
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+const DEFAULT_INPUT_DIR: &str = "files";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+const YEAR: u32 = 2021;
+
+// Names inputs were kept under before being keyed by day, so older input directories can still be imported
+const LEGACY_INPUT_NAMES: [(&str, u32); 20] = [
+    ("sonar_input", 1), ("dive_input", 2), ("binary_diagnostic_input", 3), ("bingo_input", 4),
+    ("lines_input", 5), ("lanternfish_init_input", 6), ("crab_position_input", 7), ("7_segment_input", 8),
+    ("heightmap_input", 9), ("syntax_chunk_input", 10), ("octopus_energy_level_input", 11), ("cave_path_input", 12),
+    ("origami_input", 13), ("polymer_input", 14), ("chiton_risk_input", 15), ("hex_transmission_input", 16),
+    ("probe_launch_input", 17), ("snailfish_num_input", 18), ("monad_input", 24), ("cucumber_state_input", 25),
+];
+
+// Flags asking a day binary to print its working, any other argument starting with - except - itself is also a flag
+const VERBOSE_FLAGS: [&str; 2] = ["--verbose", "-v"];
+
+// Stdin can only be read once, so keep it around for anything else reading it in the same run
+static STDIN_CONTENTS: OnceLock<String> = OnceLock::new();
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum InputSource {
+    Stdin,
+    File(PathBuf),
+
+    // A day's file in the cache, which may not have been fetched yet
+    Cache {
+        day: u32,
+        path: PathBuf,
+    },
+}
+
+// Where and how to download input that is not cached yet
+pub struct FetchConfig {
+    pub base_url: String,
+    pub session: String,
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "-"),
+            InputSource::File(path) | InputSource::Cache { path, .. } => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+pub fn day_file_name(day: u32) -> String {
+    return format!("day{:02}.txt", day);
+}
+
+// Find which day an input file is for from its name, either a cached dayNN.txt name or an older input name
+pub fn day_from_file_name(file_name: &str) -> Option<u32> {
+    for (legacy_name, day) in LEGACY_INPUT_NAMES {
+        if file_name == legacy_name {
+            return Some(day);
+        }
+    }
+
+    let day_str = file_name.strip_prefix("day")?;
+    let day_str = day_str.strip_suffix(".txt").unwrap_or(day_str);
+
+    match day_str.parse::<u32>() {
+        Ok(day) if (1..=25).contains(&day) => return Some(day),
+        _ => return None,
+    }
+}
+
+// The directory cached input is kept in
+pub fn cache_dir() -> PathBuf {
+    if let Some(input_dir) = env::var_os(INPUT_DIR_VAR) {
+        return PathBuf::from(input_dir);
+    }
+
+    let local_dir = Path::new(DEFAULT_INPUT_DIR);
+
+    if local_dir.is_dir() {
+        return local_dir.to_path_buf();
+    }
+
+    return Path::new(env!("CARGO_MANIFEST_DIR")).join(DEFAULT_INPUT_DIR);
+}
+
+// Get the fetch settings from the environment, if a session token has been given
+pub fn fetch_config_from_env() -> Option<FetchConfig> {
+    let session = env::var(SESSION_VAR).ok().filter(|session| !session.is_empty())?;
+    let base_url = env::var(BASE_URL_VAR).unwrap_or(DEFAULT_BASE_URL.to_string());
+
+    return Some(FetchConfig {
+        base_url,
+        session,
+    });
+}
+
+// Determine where to read input from given an optional path and the day
+pub fn resolve_input(path_arg: Option<&str>, day: u32) -> InputSource {
+    if let Some(path) = path_arg {
+        if path == "-" {
+            return InputSource::Stdin;
+        }
+
+        return InputSource::File(PathBuf::from(path));
+    }
+
+    return InputSource::Cache {
+        day,
+        path: cache_dir().join(day_file_name(day)),
+    };
+}
+
+// Download a day's input and save it at the given cache path
+pub fn fetch_into_cache(day: u32, path: &Path, config: &FetchConfig) -> io::Result<String> {
+    let input_contents = fetch::fetch_input(&config.base_url, &config.session, YEAR, day)?;
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    fs::write(path, &input_contents)?;
+    return Ok(input_contents);
+}
+
+pub fn read_input(source: &InputSource) -> io::Result<String> {
+    match source {
+        InputSource::Stdin => {
+            if let Some(input_contents) = STDIN_CONTENTS.get() {
+                return Ok(input_contents.clone());
+            }
+
+            let mut input_contents = String::new();
+            io::stdin().read_to_string(&mut input_contents)?;
+            return Ok(STDIN_CONTENTS.get_or_init(|| input_contents).clone());
+        },
+        InputSource::File(path) => return fs::read_to_string(path),
+        InputSource::Cache { day, path } => {
+            if path.exists() {
+                return fs::read_to_string(path);
+            }
+
+            match fetch_config_from_env() {
+                Some(config) => return fetch_into_cache(*day, path, &config),
+                None => return Err(io::Error::new(io::ErrorKind::NotFound,
+                    format!("no input for day {} at {}, import one or set {} to fetch it", day, path.display(), SESSION_VAR))),
+            }
+        },
+    }
+}
+
+// Copy an input file into the cache directory for the given day
+pub fn import_file(source_path: &Path, day: u32, cache_dir: &Path) -> io::Result<PathBuf> {
+    let cache_path = cache_dir.join(day_file_name(day));
+
+    fs::create_dir_all(cache_dir)?;
+    fs::copy(source_path, &cache_path)?;
+
+    return Ok(cache_path);
+}
+
+// Copy every file in a directory named after a day into the cache directory, returning the days imported
+pub fn import_dir(source_dir: &Path, cache_dir: &Path) -> io::Result<Vec<u32>> {
+    let mut days = Vec::<u32>::new();
+
+    for entry in fs::read_dir(source_dir)? {
+        let entry = entry?;
+
+        if !entry.file_type()?.is_file() {
+            continue;
+        }
+
+        if let Some(day) = entry.file_name().to_str().and_then(day_from_file_name) {
+            import_file(&entry.path(), day, cache_dir)?;
+            days.push(day);
+        }
+    }
+
+    days.sort();
+    return Ok(days);
+}
+
+fn is_flag(arg: &str) -> bool {
+    return arg.starts_with('-') && arg != "-";
+}
+
+fn path_from_args() -> Option<String> {
+    return env::args().skip(1).find(|arg| !is_flag(arg));
+}
+
+// Read input for a day binary, taking the path from the first non-flag command line argument if given
+pub fn input_from_args(day: u32) -> io::Result<String> {
+    return read_input(&resolve_input(path_from_args().as_deref(), day));
+}
+
+// Read input for a binary that takes something other than a day's puzzle input, such as day 16's expressions,
+// reading stdin when no path is given
+pub fn input_or_stdin_from_args() -> io::Result<String> {
+    return read_input(&path_from_args().map_or(InputSource::Stdin, |path| resolve_input(Some(&path), 0)));
+}
+
+// Check whether a day binary was asked to print its working to stderr
+pub fn verbose_from_args() -> bool {
+    return env::args().skip(1).any(|arg| VERBOSE_FLAGS.contains(&arg.as_str()));
+}
+
+// Check whether a day binary was given a flag of its own, such as day 16's --dump
+pub fn flag_from_args(flag: &str) -> bool {
+    return env::args().skip(1).any(|arg| arg == flag);
+}
+
+// Get the value of a day binary's flag written as --flag=value
+pub fn flag_value_from_args(flag: &str) -> Option<String> {
+    return env::args().skip(1).find_map(|arg| arg.strip_prefix(flag)?.strip_prefix('=').map(|value| value.to_string()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explicit_path_comes_first() {
+        assert_eq!(InputSource::Stdin, resolve_input(Some("-"), 1));
+        assert_eq!(InputSource::File(PathBuf::from("other")), resolve_input(Some("other"), 1));
+        assert_eq!("other", resolve_input(Some("other"), 1).to_string());
+    }
+
+    #[test]
+    fn finds_day_from_file_name() {
+        assert_eq!(Some(3), day_from_file_name("day03.txt"));
+        assert_eq!(Some(17), day_from_file_name("day17"));
+        assert_eq!(Some(8), day_from_file_name("7_segment_input"));
+        assert_eq!(None, day_from_file_name("day26.txt"));
+        assert_eq!(None, day_from_file_name("notes.txt"));
+    }
+
+    #[test]
+    fn imports_directory() {
+        let source_dir = env::temp_dir().join(format!("aoc2021_import_source_{}", std::process::id()));
+        let cache_dir = env::temp_dir().join(format!("aoc2021_import_cache_{}", std::process::id()));
+        fs::create_dir_all(&source_dir).unwrap();
+        fs::write(source_dir.join("monad_input"), "inp w\n").unwrap();
+        fs::write(source_dir.join("day02.txt"), "forward 5\n").unwrap();
+        fs::write(source_dir.join("notes.txt"), "not an input\n").unwrap();
+
+        assert_eq!(vec![2, 24], import_dir(&source_dir, &cache_dir).unwrap());
+        assert_eq!("inp w\n", fs::read_to_string(cache_dir.join("day24.txt")).unwrap());
+        assert!(!cache_dir.join("notes.txt").exists());
+
+        fs::remove_dir_all(&source_dir).unwrap();
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+}
@@ -0,0 +1,33 @@
+// Advent of Code 2021 solutions, one module per day
+//
+// The solutions keep their original explicit style, so a few style lints are allowed crate-wide
+#![allow(clippy::needless_return, clippy::needless_range_loop, clippy::if_same_then_else)]
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day24;
+pub mod day25;
+
+pub mod answers;
+pub mod bench;
+pub mod bigint;
+pub mod error;
+pub mod fetch;
+pub mod grid;
+pub mod input;
+pub mod runner;
@@ -0,0 +1,353 @@
+// Table of every solved day and part, used to run solutions by number instead of binary name
+
+use std::io;
+use std::panic;
+use std::time::{Duration, Instant};
+
+use crate::bench::{self, PhaseTimes};
+use crate::error::{AocError, AocResult};
+use crate::input::{self, InputSource};
+use crate::*;
+
+pub struct Solver {
+    pub day: u32,
+    pub part: u32,
+
+    // The name of the standalone binary for this day and part
+    pub name: &'static str,
+
+    pub solve: fn(&str) -> AocResult<String>,
+
+    // Time input parsing and solving separately over the given number of iterations
+    pub bench: fn(&str, u32) -> AocResult<PhaseTimes>,
+}
+
+pub const SOLVERS: &[Solver] = &[
+    Solver {
+        day: 1, part: 1, name: "sonar_sweep",
+        solve: |input| day1::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day1::parse_depths, |depths| Ok(day1::count_increases(&depths))),
+    },
+    Solver {
+        day: 1, part: 2, name: "sonar_window",
+        solve: |input| day1::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day1::parse_depths, |depths| Ok(day1::count_window_increases(&depths))),
+    },
+    Solver {
+        day: 2, part: 1, name: "dive_position",
+        solve: |input| day2::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day2::parse_commands, |commands| Ok(day2::dive_position(&commands))),
+    },
+    Solver {
+        day: 2, part: 2, name: "dive_aim",
+        solve: |input| day2::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day2::parse_commands, |commands| Ok(day2::dive_aim(&commands))),
+    },
+    Solver {
+        day: 3, part: 1, name: "power_consumption",
+        solve: |input| day3::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day3::parse_diagnostic, |lines| Ok(day3::get_gamma_epsilon(&lines))),
+    },
+    Solver {
+        day: 3, part: 2, name: "life_support",
+        solve: |input| day3::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day3::parse_diagnostic, |lines| day3::get_o2_co2(&lines)),
+    },
+    Solver {
+        day: 4, part: 1, name: "squid_bingo",
+        solve: |input| day4::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day4::parse_bingo, |(calls, mut cards)| Ok(day4::find_first_winner(&calls, &mut cards))),
+    },
+    Solver {
+        day: 4, part: 2, name: "squid_bingo_loss",
+        solve: |input| day4::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day4::parse_bingo, |(calls, mut cards)| Ok(day4::find_last_winner(&calls, &mut cards))),
+    },
+    Solver {
+        day: 5, part: 1, name: "hydrothermal_line_overlap",
+        solve: |input| day5::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, |input| day5::parse_lines(input, false), |lines| Ok(day5::count_overlapping_points(&lines))),
+    },
+    Solver {
+        day: 5, part: 2, name: "hydrothermal_diag_overlap",
+        solve: |input| day5::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, |input| day5::parse_lines(input, true), |lines| Ok(day5::count_overlapping_points(&lines))),
+    },
+    Solver {
+        day: 6, part: 1, name: "lanternfish_growth",
+        solve: |input| day6::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day6::parse_fish_state, |fish_state| Ok(day6::simulate_fish(&fish_state, 80))),
+    },
+    Solver {
+        day: 6, part: 2, name: "lanternfish_big_growth",
+        solve: |input| day6::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day6::parse_fish_state, |fish_state| Ok(day6::count_fish_descendants(&fish_state, 256))),
+    },
+    Solver {
+        day: 7, part: 1, name: "crab_fuel_position",
+        solve: |input| day7::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day7::parse_crab_positions, |crab_posns| Ok(day7::get_fuel_to_median(&crab_posns))),
+    },
+    Solver {
+        day: 7, part: 2, name: "crab_weighted_fuel_position",
+        solve: |input| day7::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day7::parse_crab_positions, |crab_posns| Ok(day7::get_weighted_fuel(&crab_posns))),
+    },
+    Solver {
+        day: 8, part: 1, name: "segment_simple_nums",
+        solve: |input| day8::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day8::parse_entries, |entries| Ok(day8::count_simple_digits(&entries))),
+    },
+    Solver {
+        day: 8, part: 2, name: "segment_full_sum",
+        solve: |input| day8::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day8::parse_entries, |entries| day8::sum_output_values(&entries)),
+    },
+    Solver {
+        day: 9, part: 1, name: "smoke_local_mins",
+        solve: |input| day9::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day9::build_heightmap, |heightmap| Ok(day9::get_total_risk(&heightmap))),
+    },
+    Solver {
+        day: 9, part: 2, name: "smoke_basins",
+        solve: |input| day9::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day9::build_heightmap, |mut heightmap| Ok(day9::get_basin_sizes(&mut heightmap))),
+    },
+    Solver {
+        day: 10, part: 1, name: "wrong_character_parsing",
+        solve: |input| day10::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day10::parse_chunk_lines, |lines| Ok(day10::get_total_corrupted_score(&lines))),
+    },
+    Solver {
+        day: 10, part: 2, name: "character_completion",
+        solve: |input| day10::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day10::parse_chunk_lines, |lines| day10::get_middle_autocomplete_score(&lines)),
+    },
+    Solver {
+        day: 11, part: 1, name: "octopus_flash_count",
+        solve: |input| day11::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day11::build_octomap, |mut octomap| Ok(day11::count_flashes(&mut octomap, 100))),
+    },
+    Solver {
+        day: 11, part: 2, name: "octopus_flash_sync",
+        solve: |input| day11::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day11::build_octomap, |mut octomap| Ok(day11::find_sync_step(&mut octomap))),
+    },
+    Solver {
+        day: 12, part: 1, name: "path_count",
+        solve: |input| day12::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day12::build_caves, |(mut caves, start_index)| Ok(day12::get_num_paths_to_end(start_index, &mut caves))),
+    },
+    Solver {
+        day: 12, part: 2, name: "path_count_repeat",
+        solve: |input| day12::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day12::build_caves, |(mut caves, start_index)| Ok(day12::get_num_paths_to_end_with_repeat(start_index, &mut caves, false, false))),
+    },
+    Solver {
+        day: 13, part: 1, name: "origami_first_fold",
+        solve: |input| day13::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day13::parse_origami, |(mut points, folds)| {
+            let first_fold = folds.first().ok_or_else(|| AocError::NoSolution("no folds given".to_string()))?;
+            day13::fold_points(first_fold, &mut points);
+            return Ok(points.len());
+        }),
+    },
+    Solver {
+        day: 13, part: 2, name: "origami_fold",
+        solve: day13::part2,
+        bench: |input, iterations| bench::time_phases(input, iterations, day13::parse_origami, |(mut points, folds)| {
+            for fold in &folds {
+                day13::fold_points(fold, &mut points);
+            }
+
+            return Ok(points);
+        }),
+    },
+    Solver {
+        day: 14, part: 1, name: "polymer_element_min_max",
+        solve: |input| day14::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day14::parse_polymer, |(mut elements, transfers)| {
+            for _ in 0..10 {
+                day14::insert_elements(&mut elements, &transfers);
+            }
+
+            return Ok(elements);
+        }),
+    },
+    Solver {
+        day: 14, part: 2, name: "polymer_element_big_min_max",
+        solve: |input| day14::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day14::parse_polymer, |(elements, transfers)| Ok(day14::count_pairs_after_steps(&elements, &transfers, 40))),
+    },
+    Solver {
+        day: 15, part: 1, name: "chiton_lowest_risk_path",
+        solve: |input| day15::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day15::parse_risk_map, |risk_map| Ok(day15::get_lowest_risk(&risk_map))),
+    },
+    Solver {
+        day: 15, part: 2, name: "chiton_lowest_risk_big_path",
+        solve: |input| day15::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day15::parse_risk_map, |risk_map| Ok(day15::get_lowest_risk(&day15::expand_risk_map(&risk_map)))),
+    },
+    Solver {
+        day: 16, part: 1, name: "packet_version_addition",
+        solve: |input| day16::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day16::parse_transmission, |transmission| Ok(day16::count_version_numbers(&day16::validate_transmission(&transmission, day16::DEFAULT_MAX_DEPTH)?))),
+    },
+    Solver {
+        day: 16, part: 2, name: "packet_solve",
+        solve: |input| day16::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day16::parse_transmission, |transmission| day16::solve_packet(&day16::validate_transmission(&transmission, day16::DEFAULT_MAX_DEPTH)?)),
+    },
+    Solver {
+        day: 17, part: 1, name: "probe_launch_height",
+        solve: |input| day17::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day17::parse_target, |target| Ok(day17::find_max_height(&target))),
+    },
+    Solver {
+        day: 17, part: 2, name: "probe_on_target",
+        solve: |input| day17::part2(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day17::parse_target, |target| Ok(day17::count_on_target(&target))),
+    },
+    Solver {
+        day: 24, part: 1, name: "alu_largest_model_number",
+        solve: day24::part1,
+        bench: |input, iterations| bench::time_phases(input, iterations, day24::build_optimized_operations, |vars| day24::alu_any_solve(vars[3].top, &vars, &day24::default_domains(&vars), 0, day24::Preference::Largest)),
+    },
+    Solver {
+        day: 24, part: 2, name: "alu_smallest_model_number",
+        solve: day24::part2,
+        bench: |input, iterations| bench::time_phases(input, iterations, day24::build_optimized_operations, |vars| day24::alu_any_solve(vars[3].top, &vars, &day24::default_domains(&vars), 0, day24::Preference::Smallest)),
+    },
+    Solver {
+        day: 25, part: 1, name: "cucumber_stop",
+        solve: |input| day25::part1(input).map(|answer| answer.to_string()),
+        bench: |input, iterations| bench::time_phases(input, iterations, day25::parse_cucumber_map, |mut cucumber_map| Ok(day25::count_steps_until_stopped(&mut cucumber_map))),
+    },
+];
+
+pub struct RunResult {
+    // The answer, or a description of why there is none
+    pub answer: Result<String, String>,
+    pub elapsed: Duration,
+    pub input: InputSource,
+
+    // Set when the input does not exist, rather than failing to read or solve
+    pub input_missing: bool,
+}
+
+// Get every solver matching the day and part, None matches all
+pub fn find_solvers(day: Option<u32>, part: Option<u32>) -> Vec<&'static Solver> {
+    let mut solvers = Vec::<&Solver>::new();
+
+    for solver in SOLVERS {
+        if day.is_none_or(|d| d == solver.day) && part.is_none_or(|p| p == solver.part) {
+            solvers.push(solver);
+        }
+    }
+
+    return solvers;
+}
+
+// Read the solver's input and time how long it takes to solve, catching any panic along the way
+// The input path overrides the solver's default input when given
+pub fn run_solver(solver: &Solver, input_path: Option<&str>) -> RunResult {
+    let source = input::resolve_input(input_path, solver.day);
+
+    let input_contents = match input::read_input(&source) {
+        Ok(contents) => contents,
+        Err(err) => {
+            return RunResult {
+                answer: Err(format!("Unable to read from input: {}", err)),
+                elapsed: Duration::ZERO,
+                input: source,
+                input_missing: err.kind() == io::ErrorKind::NotFound,
+            };
+        },
+    };
+
+    let start = Instant::now();
+    let answer = panic::catch_unwind(|| (solver.solve)(&input_contents));
+    let elapsed = start.elapsed();
+
+    let answer = match answer {
+        Ok(Ok(answer)) => Ok(answer),
+        Ok(Err(err)) => Err(err.to_string()),
+        Err(_) => Err("Solver panicked".to_string()),
+    };
+
+    return RunResult {
+        answer,
+        elapsed,
+        input: source,
+        input_missing: false,
+    };
+}
+
+// Escape a string for use inside a JSON string literal
+fn json_escape(text: &str) -> String {
+    let mut escaped = String::new();
+
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    return escaped;
+}
+
+// Format a result as a single line JSON object, answers are always strings and failures give a null answer and an error
+pub fn result_to_json(solver: &Solver, result: &RunResult) -> String {
+    let answer = match &result.answer {
+        Ok(answer) => format!("\"answer\":\"{}\"", json_escape(answer)),
+        Err(err) => format!("\"answer\":null,\"error\":\"{}\"", json_escape(err)),
+    };
+
+    return format!("{{\"day\":{},\"part\":{},{},\"elapsed_ms\":{:.3},\"input\":\"{}\"}}",
+        solver.day, solver.part, answer, result.elapsed.as_secs_f64() * 1000.0, json_escape(&result.input.to_string()));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn formats_results_as_json() {
+        let solver = &find_solvers(Some(13), Some(2))[0];
+
+        let result = RunResult {
+            answer: Ok("# #\n\"#\"\n".to_string()),
+            elapsed: Duration::from_micros(1500),
+            input: InputSource::File(PathBuf::from("files/origami_input")),
+            input_missing: false,
+        };
+
+        assert_eq!("{\"day\":13,\"part\":2,\"answer\":\"# #\\n\\\"#\\\"\\n\",\"elapsed_ms\":1.500,\"input\":\"files/origami_input\"}",
+            result_to_json(solver, &result));
+
+        let result = RunResult {
+            answer: Err("bad input: no folds given".to_string()),
+            elapsed: Duration::ZERO,
+            input: InputSource::Stdin,
+            input_missing: false,
+        };
+
+        assert_eq!("{\"day\":13,\"part\":2,\"answer\":null,\"error\":\"bad input: no folds given\",\"elapsed_ms\":0.000,\"input\":\"-\"}",
+            result_to_json(solver, &result));
+    }
+
+    #[test]
+    fn tells_missing_input_apart() {
+        let solver = &find_solvers(Some(13), Some(1))[0];
+        assert!(run_solver(solver, Some("files/no_such_input")).input_missing);
+        assert!(!run_solver(solver, Some("files")).input_missing);
+    }
+}
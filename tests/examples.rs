@@ -0,0 +1,142 @@
+// Every day's parts run against the examples given in the puzzle descriptions
+
+#![allow(clippy::needless_return)]
+
+use std::fs;
+use std::path::Path;
+
+use lvoytek_advent_of_code_2021::*;
+
+fn example(day: u32) -> String {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("files/examples").join(input::day_file_name(day));
+    return fs::read_to_string(path).expect("Unable to read example");
+}
+
+#[test]
+fn day1() {
+    let input = example(1);
+    assert_eq!(Ok(7), day1::part1(&input));
+    assert_eq!(Ok(5), day1::part2(&input));
+}
+
+#[test]
+fn day2() {
+    let input = example(2);
+    assert_eq!(Ok(150), day2::part1(&input));
+    assert_eq!(Ok(900), day2::part2(&input));
+}
+
+#[test]
+fn day3() {
+    let input = example(3);
+    assert_eq!(Ok(198), day3::part1(&input));
+    assert_eq!(Ok(230), day3::part2(&input));
+}
+
+#[test]
+fn day4() {
+    let input = example(4);
+    assert_eq!(Ok(4512), day4::part1(&input));
+    assert_eq!(Ok(1924), day4::part2(&input));
+}
+
+#[test]
+fn day5() {
+    let input = example(5);
+    assert_eq!(Ok(5), day5::part1(&input));
+    assert_eq!(Ok(12), day5::part2(&input));
+}
+
+#[test]
+fn day6() {
+    let input = example(6);
+    assert_eq!(Ok(5934), day6::part1(&input));
+}
+
+#[test]
+#[ignore = "takes around a minute"]
+fn day6_part2() {
+    assert_eq!(Ok(26984457539), day6::part2(&example(6)));
+}
+
+#[test]
+fn day7() {
+    let input = example(7);
+    assert_eq!(Ok(37), day7::part1(&input));
+    assert_eq!(Ok(168), day7::part2(&input));
+}
+
+#[test]
+fn day8() {
+    let input = example(8);
+    assert_eq!(Ok(26), day8::part1(&input));
+    assert_eq!(Ok(61229), day8::part2(&input));
+}
+
+#[test]
+fn day9() {
+    let input = example(9);
+    assert_eq!(Ok(15), day9::part1(&input));
+    assert_eq!(Ok(1134), day9::part2(&input));
+}
+
+#[test]
+fn day10() {
+    let input = example(10);
+    assert_eq!(Ok(26397), day10::part1(&input));
+    assert_eq!(Ok(288957), day10::part2(&input));
+}
+
+#[test]
+fn day11() {
+    let input = example(11);
+    assert_eq!(Ok(1656), day11::part1(&input));
+    assert_eq!(Ok(195), day11::part2(&input));
+}
+
+#[test]
+fn day12() {
+    let input = example(12);
+    assert_eq!(Ok(10), day12::part1(&input));
+    assert_eq!(Ok(36), day12::part2(&input));
+}
+
+#[test]
+fn day13() {
+    let input = example(13);
+    assert_eq!(Ok(17), day13::part1(&input));
+    assert_eq!(Ok("#####\n#   #\n#   #\n#   #\n#####\n".to_string()), day13::part2(&input));
+}
+
+#[test]
+fn day14() {
+    let input = example(14);
+    assert_eq!(Ok(1588), day14::part1(&input));
+    assert_eq!(Ok(2188189693529), day14::part2(&input));
+}
+
+#[test]
+fn day15() {
+    let input = example(15);
+    assert_eq!(Ok(40), day15::part1(&input));
+    assert_eq!(Ok(315), day15::part2(&input));
+}
+
+#[test]
+fn day16() {
+    let input = example(16);
+    assert_eq!(Ok(20), day16::part1(&input));
+    assert_eq!(Ok(1), day16::part2(&input));
+}
+
+#[test]
+fn day17() {
+    let input = example(17);
+    assert_eq!(Ok(45), day17::part1(&input));
+    assert_eq!(Ok(112), day17::part2(&input));
+}
+
+#[test]
+fn day25() {
+    assert_eq!(Ok(58), day25::part1(&example(25)));
+}
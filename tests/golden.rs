@@ -0,0 +1,154 @@
+// Every solver run through the runner against the committed puzzle inputs in files/
+
+use std::fs;
+use std::path::Path;
+
+use lvoytek_advent_of_code_2021::answers::{self, Answers};
+use lvoytek_advent_of_code_2021::runner;
+
+fn check(day: u32, part: u32, expected: &str) {
+    let solvers = runner::find_solvers(Some(day), Some(part));
+    assert_eq!(1, solvers.len());
+
+    let result = runner::run_solver(solvers[0], None);
+    assert_eq!(Ok(expected.to_string()), result.answer, "day {} part {}", day, part);
+}
+
+// The answers aoc2021 verify checks against cover every solver that has a known answer
+#[test]
+fn answers_file_is_complete() {
+    for input_dir in ["files", "files/examples"] {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(input_dir).join(answers::ANSWERS_FILE_NAME);
+        let answers = Answers::parse(&fs::read_to_string(path).unwrap()).unwrap();
+
+        // There is no example input for day 24
+        for solver in runner::SOLVERS.iter().filter(|solver| solver.day != 24 || input_dir == "files") {
+            assert!(answers.get(solver.day, solver.part).is_some(), "{} is missing day {} part {}", input_dir, solver.day, solver.part);
+        }
+    }
+}
+
+#[test]
+fn day1() {
+    check(1, 1, "1400");
+    check(1, 2, "1429");
+}
+
+#[test]
+fn day2() {
+    check(2, 1, "2036120");
+    check(2, 2, "2015547716");
+}
+
+#[test]
+fn day3() {
+    check(3, 1, "4118544");
+    check(3, 2, "3832770");
+}
+
+#[test]
+fn day4() {
+    check(4, 1, "22680");
+    check(4, 2, "16168");
+}
+
+#[test]
+fn day5() {
+    check(5, 1, "7438");
+    check(5, 2, "21406");
+}
+
+#[test]
+fn day6() {
+    check(6, 1, "395627");
+}
+
+#[test]
+#[ignore = "takes over a minute"]
+fn day6_part2() {
+    check(6, 2, "1767323539209");
+}
+
+#[test]
+fn day7() {
+    check(7, 1, "352331");
+    check(7, 2, "99266250");
+}
+
+#[test]
+fn day8() {
+    check(8, 1, "488");
+    check(8, 2, "1040429");
+}
+
+#[test]
+fn day9() {
+    check(9, 1, "423");
+    check(9, 2, "1198704");
+}
+
+#[test]
+fn day10() {
+    check(10, 1, "278475");
+    check(10, 2, "3015539998");
+}
+
+#[test]
+fn day11() {
+    check(11, 1, "1603");
+    check(11, 2, "222");
+}
+
+#[test]
+fn day12() {
+    check(12, 1, "3563");
+    check(12, 2, "105453");
+}
+
+#[test]
+fn day13() {
+    check(13, 1, "708");
+    check(13, 2, concat!(
+        "#### ###  #    #  # ###  ###  #### #  #\n",
+        "#    #  # #    #  # #  # #  # #    #  #\n",
+        "###  ###  #    #  # ###  #  # ###  ####\n",
+        "#    #  # #    #  # #  # ###  #    #  #\n",
+        "#    #  # #    #  # #  # # #  #    #  #\n",
+        "#### ###  ####  ##  ###  #  # #    #  #\n",
+    ));
+}
+
+#[test]
+fn day14() {
+    check(14, 1, "2003");
+    check(14, 2, "2276644000111");
+}
+
+#[test]
+fn day15() {
+    check(15, 1, "363");
+    check(15, 2, "2835");
+}
+
+#[test]
+fn day16() {
+    check(16, 1, "843");
+    check(16, 2, "5390807940351");
+}
+
+#[test]
+fn day17() {
+    check(17, 1, "7875");
+    check(17, 2, "2321");
+}
+
+#[test]
+fn day24() {
+    check(24, 1, "99394899891971");
+    check(24, 2, "92171126131911");
+}
+
+#[test]
+fn day25() {
+    check(25, 1, "482");
+}